@@ -4,7 +4,7 @@
 //! iteration separators, termination messages, event tables,
 //! and other terminal UI elements.
 
-use ralph_core::{EventRecord, TerminationReason};
+use ralph_core::{EventRecord, HatAnalytics, TerminationReason};
 use ralph_proto::HatId;
 use std::collections::HashMap;
 use std::time::Duration;
@@ -291,6 +291,68 @@ pub fn print_events_table(records: &[EventRecord], use_colors: bool) {
     }
 }
 
+/// Prints per-hat time totals and the hat transition matrix.
+pub fn print_hat_analytics(analytics: &HatAnalytics, use_colors: bool) {
+    use colors::*;
+
+    if analytics.hats.is_empty() {
+        println!("No hat activity found (no events triggered a hat).");
+        return;
+    }
+
+    if use_colors {
+        println!("{BOLD}{DIM}Hat           | Active       | Share | Activations{RESET}");
+        println!("{DIM}--------------|--------------|-------|------------{RESET}");
+    } else {
+        println!("Hat           | Active       | Share | Activations");
+        println!("--------------|--------------|-------|------------");
+    }
+
+    for hat in &analytics.hats {
+        println!(
+            "{:<13} | {:<12} | {:>4.0}% | {}",
+            truncate(&hat.hat, 13),
+            format_elapsed(Duration::from_millis(hat.active_ms)),
+            hat.share * 100.0,
+            hat.activations
+        );
+    }
+
+    let mut transitions: Vec<(&str, &str, usize)> = analytics
+        .matrix
+        .iter()
+        .flat_map(|(from, row)| {
+            row.iter()
+                .map(move |(to, count)| (from.as_str(), to.as_str(), *count))
+        })
+        .collect();
+    transitions.sort_by(|a, b| b.2.cmp(&a.2).then((a.0, a.1).cmp(&(b.0, b.1))));
+
+    if !transitions.is_empty() {
+        if use_colors {
+            println!("\n{BOLD}Transitions{RESET}");
+        } else {
+            println!("\nTransitions");
+        }
+        for (from, to, count) in transitions {
+            println!("  {from} → {to}: {count}");
+        }
+    }
+
+    let total = format_elapsed(Duration::from_millis(analytics.total_ms));
+    if use_colors {
+        println!(
+            "\n{DIM}Total: {total} across {} transitions{RESET}",
+            analytics.transitions
+        );
+    } else {
+        println!(
+            "\nTotal: {total} across {} transitions",
+            analytics.transitions
+        );
+    }
+}
+
 /// Builds a map of event topics to hat display information for the TUI.
 ///
 /// This allows the TUI to dynamically resolve which hat should be displayed
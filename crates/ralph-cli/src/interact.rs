@@ -2,9 +2,14 @@
 //!
 //! Provides non-blocking notification tools for agents:
 //! - `ralph tools interact progress "message"` — Send a progress update via Telegram
+//! - `ralph tools interact history` — Show answered and expired questions
+//! - `ralph tools interact stats` — Summarize question volume and response times
+
+use std::path::{Path, PathBuf};
 
 use anyhow::{Context, Result};
 use clap::{Parser, Subcommand};
+use ralph_core::{QuestionStatus, QuestionStore};
 
 use crate::bot;
 
@@ -18,6 +23,12 @@ pub struct InteractArgs {
 pub enum InteractCommands {
     /// Send a non-blocking progress update via Telegram
     Progress(ProgressArgs),
+
+    /// Show answered and expired questions with their responses
+    History(HistoryArgs),
+
+    /// Summarize question volume, response latency, and timeout rate
+    Stats(StatsArgs),
 }
 
 #[derive(Parser, Debug)]
@@ -26,10 +37,126 @@ pub struct ProgressArgs {
     pub message: String,
 }
 
+#[derive(Parser, Debug)]
+pub struct HistoryArgs {
+    /// Include questions still awaiting a response
+    #[arg(long, short = 'a')]
+    pub all: bool,
+
+    /// Limit the number of questions displayed
+    #[arg(long, short = 'l')]
+    pub limit: Option<usize>,
+
+    /// Output as JSON
+    #[arg(long)]
+    pub json: bool,
+
+    /// Working directory (default: current directory)
+    #[arg(long)]
+    pub root: Option<PathBuf>,
+}
+
+#[derive(Parser, Debug)]
+pub struct StatsArgs {
+    /// Output as JSON
+    #[arg(long)]
+    pub json: bool,
+
+    /// Working directory (default: current directory)
+    #[arg(long)]
+    pub root: Option<PathBuf>,
+}
+
 pub async fn execute(args: InteractArgs) -> Result<()> {
     match args.command {
         InteractCommands::Progress(progress_args) => send_progress(progress_args).await,
+        InteractCommands::History(history_args) => show_history(&history_args),
+        InteractCommands::Stats(stats_args) => show_stats(&stats_args),
+    }
+}
+
+fn load_store(root: Option<&Path>) -> Result<QuestionStore> {
+    let root = root.unwrap_or(Path::new("."));
+    let path = root.join(".ralph").join("agent").join("questions.jsonl");
+    QuestionStore::load(&path).context("Failed to load questions")
+}
+
+fn show_history(args: &HistoryArgs) -> Result<()> {
+    let store = load_store(args.root.as_deref())?;
+
+    let mut questions = store.history();
+    if args.all {
+        questions.splice(0..0, store.pending());
     }
+    if let Some(limit) = args.limit {
+        questions.truncate(limit);
+    }
+
+    if args.json {
+        println!("{}", serde_json::to_string_pretty(&questions)?);
+        return Ok(());
+    }
+
+    if questions.is_empty() {
+        println!("No questions found");
+        return Ok(());
+    }
+    for question in questions {
+        let status = match question.status {
+            QuestionStatus::Pending => "pending",
+            QuestionStatus::Answered => "answered",
+            QuestionStatus::Expired => "expired",
+        };
+        println!(
+            "{} [{}] {}",
+            question.asked_at.format("%Y-%m-%d %H:%M"),
+            status,
+            question.text
+        );
+        if let Some(response) = &question.response {
+            println!("    -> {}", response);
+        }
+    }
+    Ok(())
+}
+
+fn show_stats(args: &StatsArgs) -> Result<()> {
+    let stats = load_store(args.root.as_deref())?.stats();
+
+    if args.json {
+        println!("{}", serde_json::to_string_pretty(&stats)?);
+        return Ok(());
+    }
+
+    if stats.total == 0 {
+        println!("No questions found");
+        return Ok(());
+    }
+    println!(
+        "Questions: {} ({} answered, {} expired, {} pending)",
+        stats.total, stats.answered, stats.expired, stats.pending
+    );
+    println!("Timeout rate: {:.0}%", stats.timeout_rate * 100.0);
+    if let Some(secs) = stats.median_response_secs {
+        println!("Median response: {}m {}s", secs / 60, secs % 60);
+    }
+    println!("Per day:");
+    for (day, count) in &stats.per_day {
+        println!("    {day}  {count}");
+    }
+    println!("Per loop:");
+    for (loop_id, count) in &stats.per_loop {
+        println!("    {loop_id}  {count}");
+    }
+    println!("Per hat:");
+    for (hat, count) in &stats.per_hat {
+        println!("    {hat}  {count}");
+    }
+    println!("Per preset:");
+    for (preset, count) in &stats.per_preset {
+        println!("    {preset}  {count}");
+    }
+    Ok(())
 }
 
 async fn send_progress(args: ProgressArgs) -> Result<()> {
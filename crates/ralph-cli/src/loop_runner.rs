@@ -129,6 +129,19 @@ pub async fn run_loop_impl(
 
         debug!("Created events file for this run: {}", relative_events_path);
 
+        // Prune event log archives outside the configured retention policy
+        match ralph_core::enforce_retention(
+            &ctx.ralph_dir(),
+            &config.features.archive,
+            chrono::Utc::now(),
+        ) {
+            Ok(removed) if !removed.is_empty() => {
+                debug!("Pruned {} expired event log archive(s)", removed.len());
+            }
+            Ok(_) => {}
+            Err(e) => warn!("Failed to enforce archive retention: {}", e),
+        }
+
         // Clear scratchpad for fresh objective start
         // Stale content from previous runs can confuse the agent about current task state
         let scratchpad_path = ctx.scratchpad_path();
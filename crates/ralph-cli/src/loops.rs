@@ -11,16 +11,19 @@
 //! - `stop`: Terminate running loop
 //! - `prune`: Clean up stale loops
 //! - `attach`: Open shell in worktree
-//! - `diff`: Show changes from merge-base
+//! - `diff`: Show changes from merge-base (`--json` for a structured summary)
+//! - `export`: Dump merge queue history as CSV or JSON for external dashboards
 
 use std::path::PathBuf;
 use std::process::Command;
 
 use anyhow::{Context, Result, bail};
-use clap::{Parser, Subcommand};
+use clap::{Parser, Subcommand, ValueEnum};
 
 use ralph_core::worktree::{list_ralph_worktrees, remove_worktree};
-use ralph_core::{LoopRegistry, MergeButtonState, MergeQueue, MergeState, merge_button_state};
+use ralph_core::{
+    LoopHealth, LoopRegistry, MergeButtonState, MergeQueue, MergeState, merge_button_state,
+};
 
 /// Manage parallel loops.
 #[derive(Parser, Debug)]
@@ -66,6 +69,9 @@ pub enum LoopsCommands {
 
     /// Get merge button state for a loop (JSON output for web API)
     MergeButtonState(MergeButtonStateArgs),
+
+    /// Export lifecycle timestamps and diff sizes for all queued loops
+    Export(ExportArgs),
 }
 
 #[derive(Parser, Debug)]
@@ -77,6 +83,10 @@ pub struct ListArgs {
     /// Show all loops including terminal states (merged, discarded)
     #[arg(long)]
     pub all: bool,
+
+    /// Minutes without events before a running loop is reported as stalled
+    #[arg(long, default_value_t = 10)]
+    pub stall_minutes: u32,
 }
 
 #[derive(Parser, Debug)]
@@ -124,6 +134,10 @@ pub struct StopArgs {
     /// Use SIGKILL instead of SIGTERM
     #[arg(long)]
     pub force: bool,
+
+    /// Send SIGTERM now and SIGKILL if the loop is still running after this many seconds
+    #[arg(long, value_name = "SECS", conflicts_with = "force")]
+    pub kill_after: Option<u64>,
 }
 
 #[derive(Parser, Debug)]
@@ -140,8 +154,19 @@ pub struct DiffArgs {
     /// Show stat only (no diff content)
     #[arg(long)]
     pub stat: bool,
+
+    /// Output a structured JSON summary (per-file additions/deletions and patch)
+    #[arg(long)]
+    pub json: bool,
+
+    /// Maximum patch size in bytes for JSON output (0 omits the patch)
+    #[arg(long, default_value_t = DEFAULT_DIFF_PATCH_BYTES, requires = "json")]
+    pub max_patch_bytes: usize,
 }
 
+/// Default patch size limit for `ralph loops diff --json`.
+const DEFAULT_DIFF_PATCH_BYTES: usize = 100_000;
+
 #[derive(Parser, Debug)]
 pub struct MergeArgs {
     /// Loop ID
@@ -150,6 +175,14 @@ pub struct MergeArgs {
     /// Force merge even if state is 'merging'
     #[arg(long)]
     pub force: bool,
+
+    /// Perform a trial merge and report conflicts without merging
+    #[arg(long, conflicts_with = "force")]
+    pub dry_run: bool,
+
+    /// Output the dry-run result, or the conflicts of a failed merge, as JSON
+    #[arg(long)]
+    pub json: bool,
 }
 
 #[derive(Parser, Debug)]
@@ -158,6 +191,19 @@ pub struct MergeButtonStateArgs {
     pub loop_id: String,
 }
 
+#[derive(Parser, Debug)]
+pub struct ExportArgs {
+    /// Output format
+    #[arg(long, value_enum, default_value_t = ExportFormat::Json)]
+    pub format: ExportFormat,
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy)]
+pub enum ExportFormat {
+    Json,
+    Csv,
+}
+
 /// Execute a loops command.
 pub fn execute(args: LoopsArgs, use_colors: bool) -> Result<()> {
     match args.command {
@@ -165,6 +211,7 @@ pub fn execute(args: LoopsArgs, use_colors: bool) -> Result<()> {
             ListArgs {
                 json: false,
                 all: false,
+                stall_minutes: 10,
             },
             use_colors,
         ),
@@ -180,6 +227,7 @@ pub fn execute(args: LoopsArgs, use_colors: bool) -> Result<()> {
         Some(LoopsCommands::Merge(merge_args)) => merge_loop(merge_args),
         Some(LoopsCommands::Process) => process_queue(),
         Some(LoopsCommands::MergeButtonState(args)) => get_merge_button_state(args),
+        Some(LoopsCommands::Export(args)) => export_loops(&args),
     }
 }
 
@@ -283,17 +331,21 @@ fn list_loops(args: ListArgs, use_colors: bool) -> Result<()> {
                     prompt: truncate(&metadata.prompt, 40),
                     age: None,   // Primary loop age not easily available
                     merge: None, // Primary loop doesn't have merge state
+                    health: None,
+                    last_event_at: None,
                 });
             }
         }
     }
 
     // Add running loops from registry
+    let stall_after = chrono::Duration::minutes(i64::from(args.stall_minutes));
     for entry in &loop_entries {
-        let status = if entry.is_alive() {
-            "running"
-        } else {
-            "crashed"
+        let health = entry.health(now, stall_after);
+        let status = match health {
+            LoopHealth::Running => "running",
+            LoopHealth::Stalled => "stalled",
+            LoopHealth::Dead => "crashed",
         };
 
         let location = entry
@@ -307,8 +359,10 @@ fn list_loops(args: ListArgs, use_colors: bool) -> Result<()> {
             status: status.to_string(),
             location,
             prompt: truncate(&entry.prompt, 40),
-            age: None, // Registry doesn't track start time
+            age: Some(format_age(now.signed_duration_since(entry.started))),
             merge: None,
+            health: Some(health),
+            last_event_at: entry.last_event_at(),
         });
     }
 
@@ -322,16 +376,10 @@ fn list_loops(args: ListArgs, use_colors: bool) -> Result<()> {
                 continue;
             }
 
-            let status = match entry.state {
-                MergeState::Queued => "queued",
-                MergeState::Merging => "merging",
-                MergeState::Merged => "merged",
-                MergeState::NeedsReview => {
-                    has_needs_review = true;
-                    "needs-review"
-                }
-                MergeState::Discarded => "discarded",
-            };
+            let status = merge_state_label(entry.state);
+            if entry.state == MergeState::NeedsReview {
+                has_needs_review = true;
+            }
 
             // Calculate age from entry timestamp
             let age = Some(format_age(now.signed_duration_since(entry.queued_at)));
@@ -361,6 +409,8 @@ fn list_loops(args: ListArgs, use_colors: bool) -> Result<()> {
                 prompt: truncate(&entry.prompt, 40),
                 age,
                 merge: merge_status,
+                health: None,
+                last_event_at: None,
             });
         }
     }
@@ -378,6 +428,8 @@ fn list_loops(args: ListArgs, use_colors: bool) -> Result<()> {
                     prompt: String::new(),
                     age: None,
                     merge: None,
+                    health: None,
+                    last_event_at: None,
                 });
             }
         }
@@ -483,11 +535,16 @@ struct LoopRow {
     age: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     merge: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    health: Option<LoopHealth>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    last_event_at: Option<chrono::DateTime<chrono::Utc>>,
 }
 
 fn colorize_status(status: &str) -> String {
     match status {
         "running" => format!("\x1b[32m{}\x1b[0m", status), // green
+        "stalled" => format!("\x1b[33m{}\x1b[0m", status), // yellow
         "merging" => format!("\x1b[33m{}\x1b[0m", status), // yellow
         "merged" => format!("\x1b[34m{}\x1b[0m", status),  // blue
         "needs-review" => format!("\x1b[31m{}\x1b[0m", status), // red
@@ -727,6 +784,31 @@ fn stop_loop(args: StopArgs) -> Result<()> {
         }
     }
 
+    if let Some(grace_secs) = args.kill_after {
+        #[cfg(unix)]
+        {
+            println!(
+                "Sending SIGTERM to loop '{}' (PID {}), SIGKILL after {}s...",
+                loop_id, metadata.pid, grace_secs
+            );
+            let killed =
+                terminate_process(metadata.pid, std::time::Duration::from_secs(grace_secs))?;
+            if killed {
+                println!("Loop did not exit in time; sent SIGKILL.");
+            } else {
+                println!("Loop exited.");
+            }
+            forget_stopped_loop(&cwd, &loop_id, worktree_path.as_deref());
+            return Ok(());
+        }
+
+        #[cfg(not(unix))]
+        {
+            let _ = grace_secs;
+            bail!("--kill-after is only supported on Unix systems");
+        }
+    }
+
     let stop_path = target_root.join(".ralph/stop-requested");
     if let Some(parent) = stop_path.parent() {
         std::fs::create_dir_all(parent)
@@ -742,6 +824,72 @@ fn stop_loop(args: StopArgs) -> Result<()> {
     Ok(())
 }
 
+/// Sends SIGTERM, waits up to `grace` for the process to exit, then sends SIGKILL.
+///
+/// Returns true if SIGKILL was needed.
+#[cfg(unix)]
+fn terminate_process(pid: u32, grace: std::time::Duration) -> Result<bool> {
+    use nix::sys::signal::{Signal, kill};
+    use nix::unistd::Pid;
+
+    let pid = Pid::from_raw(pid as i32);
+    kill(pid, Signal::SIGTERM).context("Failed to send SIGTERM")?;
+
+    let deadline = std::time::Instant::now() + grace;
+    while std::time::Instant::now() < deadline {
+        if process_exited(pid)? {
+            return Ok(false);
+        }
+        std::thread::sleep(std::time::Duration::from_millis(100));
+    }
+
+    if process_exited(pid)? {
+        return Ok(false);
+    }
+    kill(pid, Signal::SIGKILL).context("Failed to send SIGKILL")?;
+    Ok(true)
+}
+
+/// Returns true once `pid` no longer exists.
+///
+/// Only `ESRCH` means the process is gone; `EPERM` means it is alive but owned
+/// by someone else, so it and any other error are returned.
+#[cfg(unix)]
+fn process_exited(pid: nix::unistd::Pid) -> Result<bool> {
+    use nix::errno::Errno;
+    use nix::sys::signal::kill;
+
+    match kill(pid, None) {
+        Ok(()) => Ok(false),
+        Err(Errno::ESRCH) => Ok(true),
+        Err(e) => Err(e).with_context(|| format!("Failed to check whether process {pid} exited")),
+    }
+}
+
+/// Cleans up after `--kill-after`, whether the loop exited on SIGTERM or was
+/// killed, since in either case it may not have deregistered itself.
+///
+/// The registry entry is removed; the worktree is kept for inspection.
+/// Plain `--force` leaves the entry for `ralph loops prune`, as before.
+#[cfg(unix)]
+fn forget_stopped_loop(cwd: &std::path::Path, loop_id: &str, worktree_path: Option<&str>) {
+    let registry = LoopRegistry::new(cwd);
+    if let Ok(Some(_)) = registry.get(loop_id)
+        && let Err(e) = registry.deregister(loop_id)
+    {
+        eprintln!(
+            "Warning: failed to remove '{}' from registry: {}",
+            loop_id, e
+        );
+    }
+    if let Some(path) = worktree_path {
+        println!(
+            "Worktree kept at {}. Use `ralph loops merge {}` or `ralph loops discard {}`.",
+            path, loop_id, loop_id
+        );
+    }
+}
+
 /// Prune stale loops.
 fn prune_stale() -> Result<()> {
     let cwd = std::env::current_dir()?;
@@ -831,6 +979,13 @@ fn show_diff(args: DiffArgs) -> Result<()> {
     // Show diff from merge-base
     // Note: three-dot syntax requires both refs in a single argument: "main...branch"
     let diff_range = format!("main...{}", branch);
+
+    if args.json {
+        let summary = diff_summary(&cwd, &loop_id, &diff_range, args.max_patch_bytes)?;
+        println!("{}", serde_json::to_string_pretty(&summary)?);
+        return Ok(());
+    }
+
     let mut git_args = vec!["diff", &diff_range];
 
     if args.stat {
@@ -850,6 +1005,230 @@ fn show_diff(args: DiffArgs) -> Result<()> {
     Ok(())
 }
 
+/// A file changed on a loop branch.
+#[derive(Debug, PartialEq, Eq, serde::Serialize)]
+struct DiffFile {
+    path: String,
+    /// Added lines (`None` for binary files)
+    additions: Option<u64>,
+    /// Deleted lines (`None` for binary files)
+    deletions: Option<u64>,
+}
+
+/// Structured diff of a loop branch against its merge-base with `main`.
+#[derive(Debug, serde::Serialize)]
+struct DiffSummary {
+    loop_id: String,
+    range: String,
+    files: Vec<DiffFile>,
+    additions: u64,
+    deletions: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    patch: Option<String>,
+    patch_truncated: bool,
+}
+
+/// Builds a [`DiffSummary`] from `git diff --numstat` and, if allowed, the unified patch.
+fn diff_summary(
+    cwd: &std::path::Path,
+    loop_id: &str,
+    diff_range: &str,
+    max_patch_bytes: usize,
+) -> Result<DiffSummary> {
+    let output = Command::new("git")
+        .args(["diff", "--numstat", diff_range])
+        .current_dir(cwd)
+        .output()
+        .context("Failed to run git diff")?;
+    if !output.status.success() {
+        bail!(
+            "git diff failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    let files = parse_numstat(&String::from_utf8_lossy(&output.stdout));
+
+    let (patch, patch_truncated) = if max_patch_bytes == 0 {
+        (None, false)
+    } else {
+        let output = Command::new("git")
+            .args(["diff", diff_range])
+            .current_dir(cwd)
+            .output()
+            .context("Failed to run git diff")?;
+        if !output.status.success() {
+            bail!(
+                "git diff failed: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            );
+        }
+        let patch = String::from_utf8_lossy(&output.stdout).into_owned();
+        if patch.len() > max_patch_bytes {
+            let end = ralph_core::floor_char_boundary(&patch, max_patch_bytes);
+            (Some(patch[..end].to_string()), true)
+        } else {
+            (Some(patch), false)
+        }
+    };
+
+    Ok(DiffSummary {
+        loop_id: loop_id.to_string(),
+        range: diff_range.to_string(),
+        additions: files.iter().filter_map(|f| f.additions).sum(),
+        deletions: files.iter().filter_map(|f| f.deletions).sum(),
+        files,
+        patch,
+        patch_truncated,
+    })
+}
+
+/// Parses `git diff --numstat` output. Binary files report `-` for both counts.
+fn parse_numstat(output: &str) -> Vec<DiffFile> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let mut parts = line.splitn(3, '\t');
+            let additions = parts.next()?;
+            let deletions = parts.next()?;
+            let path = parts.next()?;
+            Some(DiffFile {
+                path: path.to_string(),
+                additions: additions.parse().ok(),
+                deletions: deletions.parse().ok(),
+            })
+        })
+        .collect()
+}
+
+/// Display label for a merge queue state.
+fn merge_state_label(state: MergeState) -> &'static str {
+    match state {
+        MergeState::Queued => "queued",
+        MergeState::Merging => "merging",
+        MergeState::Merged => "merged",
+        MergeState::NeedsReview => "needs-review",
+        MergeState::Discarded => "discarded",
+    }
+}
+
+/// One loop's lifecycle, as exported by `ralph loops export`.
+#[derive(Debug, serde::Serialize)]
+struct LoopExportRow {
+    loop_id: String,
+    state: &'static str,
+    queued_at: chrono::DateTime<chrono::Utc>,
+    merge_started_at: Option<chrono::DateTime<chrono::Utc>>,
+    resolved_at: Option<chrono::DateTime<chrono::Utc>>,
+    /// Seconds from merge start to merge commit (merged loops only)
+    merge_duration_secs: Option<i64>,
+    merge_commit: Option<String>,
+    files_changed: Option<usize>,
+    additions: Option<u64>,
+    deletions: Option<u64>,
+    prompt: String,
+}
+
+const EXPORT_CSV_HEADER: &str = "loop_id,state,queued_at,merge_started_at,resolved_at,\
+merge_duration_secs,merge_commit,files_changed,additions,deletions,prompt";
+
+impl LoopExportRow {
+    fn to_csv(&self) -> String {
+        fn opt<T: ToString>(value: Option<&T>) -> String {
+            value.map(ToString::to_string).unwrap_or_default()
+        }
+        [
+            csv_field(&self.loop_id),
+            self.state.to_string(),
+            self.queued_at.to_rfc3339(),
+            opt(self.merge_started_at.map(|t| t.to_rfc3339()).as_ref()),
+            opt(self.resolved_at.map(|t| t.to_rfc3339()).as_ref()),
+            opt(self.merge_duration_secs.as_ref()),
+            opt(self.merge_commit.as_ref()),
+            opt(self.files_changed.as_ref()),
+            opt(self.additions.as_ref()),
+            opt(self.deletions.as_ref()),
+            csv_field(&self.prompt),
+        ]
+        .join(",")
+    }
+}
+
+/// Quotes a CSV field if it contains a delimiter, quote, or line break.
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Builds export rows from the merge queue.
+///
+/// Diff sizes come from the merge commit for merged loops and from the loop
+/// branch otherwise; they are empty when neither is available.
+fn loop_export_rows(cwd: &std::path::Path) -> Result<Vec<LoopExportRow>> {
+    let entries = MergeQueue::new(cwd).list()?;
+    Ok(entries
+        .into_iter()
+        .map(|entry| {
+            let range = match &entry.merge_commit {
+                Some(commit) => format!("{commit}^1..{commit}"),
+                None => format!("main...ralph/{}", entry.loop_id),
+            };
+            let files = Command::new("git")
+                .args(["diff", "--numstat", &range])
+                .current_dir(cwd)
+                .output()
+                .ok()
+                .filter(|output| output.status.success())
+                .map(|output| parse_numstat(&String::from_utf8_lossy(&output.stdout)));
+
+            let merge_duration_secs = match (entry.state, entry.merge_started_at, entry.resolved_at)
+            {
+                (MergeState::Merged, Some(started), Some(resolved)) => {
+                    Some((resolved - started).num_seconds())
+                }
+                _ => None,
+            };
+
+            LoopExportRow {
+                state: merge_state_label(entry.state),
+                queued_at: entry.queued_at,
+                merge_started_at: entry.merge_started_at,
+                resolved_at: entry.resolved_at,
+                merge_duration_secs,
+                merge_commit: entry.merge_commit,
+                files_changed: files.as_ref().map(Vec::len),
+                additions: files
+                    .as_ref()
+                    .map(|f| f.iter().filter_map(|f| f.additions).sum()),
+                deletions: files
+                    .as_ref()
+                    .map(|f| f.iter().filter_map(|f| f.deletions).sum()),
+                prompt: entry.prompt,
+                loop_id: entry.loop_id,
+            }
+        })
+        .collect())
+}
+
+/// Export merge queue history for external dashboards.
+fn export_loops(args: &ExportArgs) -> Result<()> {
+    let cwd = std::env::current_dir()?;
+    let rows = loop_export_rows(&cwd)?;
+
+    match args.format {
+        ExportFormat::Json => println!("{}", serde_json::to_string_pretty(&rows)?),
+        ExportFormat::Csv => {
+            println!("{EXPORT_CSV_HEADER}");
+            for row in &rows {
+                println!("{}", row.to_csv());
+            }
+        }
+    }
+    Ok(())
+}
+
 /// Merge a completed loop (or force retry).
 fn merge_loop(args: MergeArgs) -> Result<()> {
     let cwd = std::env::current_dir()?;
@@ -859,6 +1238,11 @@ fn merge_loop(args: MergeArgs) -> Result<()> {
     // Try to find the loop in various places
     let (loop_id, worktree_path) = resolve_loop(&cwd, &args.loop_id)?;
 
+    if args.dry_run {
+        let preview = merge_preview(&cwd, &loop_id)?;
+        return print_merge_preview(&preview, args.json);
+    }
+
     // 1. Check if it's running
     if let Ok(Some(entry)) = registry.get(&loop_id)
         && entry.is_alive()
@@ -919,7 +1303,192 @@ fn merge_loop(args: MergeArgs) -> Result<()> {
         }
     }
 
-    spawn_merge_ralph(&cwd, &loop_id)
+    let merge_result = spawn_merge_ralph(&cwd, &loop_id);
+    if merge_result.is_err()
+        && let Ok(preview) = merge_preview(&cwd, &loop_id)
+        && !preview.clean
+    {
+        // Report what's actually in the way, in the same shape as --dry-run
+        print_merge_preview(&preview, args.json)?;
+        bail!(
+            "Merge of loop '{}' failed: {} conflicting file(s) with main",
+            loop_id,
+            preview.conflicts.len()
+        );
+    }
+    merge_result
+}
+
+/// Result of a trial merge of a loop branch into `main`.
+#[derive(Debug, PartialEq, Eq, serde::Serialize)]
+struct MergePreview {
+    loop_id: String,
+    branch: String,
+    clean: bool,
+    /// Files with conflicts
+    conflicts: Vec<ConflictFile>,
+    /// Informational messages from git (e.g. `CONFLICT (content): ...`)
+    messages: Vec<String>,
+}
+
+/// A file that conflicts in a trial merge.
+#[derive(Debug, PartialEq, Eq, serde::Serialize)]
+struct ConflictFile {
+    path: String,
+    /// Conflicting regions; empty for conflicts without content markers
+    /// (e.g. modify/delete or binary files)
+    hunks: Vec<ConflictHunk>,
+}
+
+/// One conflicting region of a file, as marked up by git.
+#[derive(Debug, Default, PartialEq, Eq, serde::Serialize)]
+struct ConflictHunk {
+    /// 1-based line of the `<<<<<<<` marker in the merged file
+    line: usize,
+    /// Lines from `main`
+    ours: Vec<String>,
+    /// Lines from the loop branch
+    theirs: Vec<String>,
+}
+
+/// Prints a merge preview as JSON or a human-readable conflict summary.
+fn print_merge_preview(preview: &MergePreview, json: bool) -> Result<()> {
+    if json {
+        println!("{}", serde_json::to_string_pretty(preview)?);
+        return Ok(());
+    }
+    if preview.conflicts.is_empty() {
+        println!("Loop '{}' merges cleanly into main.", preview.loop_id);
+        return Ok(());
+    }
+
+    println!(
+        "Merging loop '{}' into main would conflict in {} file(s):",
+        preview.loop_id,
+        preview.conflicts.len()
+    );
+    for file in &preview.conflicts {
+        println!("  {}", file.path);
+        for hunk in &file.hunks {
+            println!(
+                "    line {}: {} line(s) in main, {} line(s) in {}",
+                hunk.line,
+                hunk.ours.len(),
+                hunk.theirs.len(),
+                preview.branch
+            );
+        }
+    }
+    for message in preview
+        .messages
+        .iter()
+        .filter(|m| m.starts_with("CONFLICT"))
+    {
+        println!("  {}", message);
+    }
+    Ok(())
+}
+
+/// Runs `git merge-tree --write-tree` to trial-merge a loop branch without
+/// touching the working tree or index.
+fn merge_preview(cwd: &std::path::Path, loop_id: &str) -> Result<MergePreview> {
+    let branch = format!("ralph/{}", loop_id);
+    let output = Command::new("git")
+        .args(["merge-tree", "--write-tree", "--name-only", "main", &branch])
+        .current_dir(cwd)
+        .output()
+        .context("Failed to run git merge-tree")?;
+
+    // Exit code 1 means conflicts; anything else non-zero is a real failure
+    let clean = match output.status.code() {
+        Some(0) => true,
+        Some(1) => false,
+        _ => bail!(
+            "git merge-tree failed (requires git 2.38+): {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        ),
+    };
+
+    let (tree, paths, messages) = parse_merge_tree(&String::from_utf8_lossy(&output.stdout));
+    let conflicts = paths
+        .into_iter()
+        .map(|path| {
+            // The merged tree holds conflicted files with their markers
+            let hunks = Command::new("git")
+                .args(["cat-file", "-p", &format!("{tree}:{path}")])
+                .current_dir(cwd)
+                .output()
+                .ok()
+                .filter(|output| output.status.success())
+                .map(|output| parse_conflict_hunks(&String::from_utf8_lossy(&output.stdout)))
+                .unwrap_or_default();
+            ConflictFile { path, hunks }
+        })
+        .collect();
+
+    Ok(MergePreview {
+        loop_id: loop_id.to_string(),
+        branch,
+        clean,
+        conflicts,
+        messages,
+    })
+}
+
+/// Parses `git merge-tree --write-tree --name-only` output.
+///
+/// The first line is the tree OID, followed by conflicted paths, a blank
+/// line, and informational messages. Returns `(tree, conflicts, messages)`.
+fn parse_merge_tree(output: &str) -> (String, Vec<String>, Vec<String>) {
+    let mut lines = output.lines();
+    let tree = lines.next().unwrap_or_default().to_string();
+    let conflicts = lines
+        .by_ref()
+        .take_while(|line| !line.is_empty())
+        .map(ToString::to_string)
+        .collect();
+    let messages = lines
+        .filter(|line| !line.is_empty())
+        .map(ToString::to_string)
+        .collect();
+    (tree, conflicts, messages)
+}
+
+/// Extracts conflict hunks from a file containing git conflict markers.
+///
+/// A `|||||||` base section (diff3 style) is skipped.
+fn parse_conflict_hunks(content: &str) -> Vec<ConflictHunk> {
+    enum Section {
+        Outside,
+        Ours,
+        Base,
+        Theirs,
+    }
+
+    let mut hunks = Vec::new();
+    let mut section = Section::Outside;
+    let mut current = ConflictHunk::default();
+    for (index, line) in content.lines().enumerate() {
+        match section {
+            Section::Outside if line.starts_with("<<<<<<<") => {
+                current.line = index + 1;
+                section = Section::Ours;
+            }
+            Section::Outside => {}
+            Section::Ours | Section::Base if line.starts_with("=======") => {
+                section = Section::Theirs;
+            }
+            Section::Ours if line.starts_with("|||||||") => section = Section::Base,
+            Section::Ours => current.ours.push(line.to_string()),
+            Section::Base => {}
+            Section::Theirs if line.starts_with(">>>>>>>") => {
+                hunks.push(std::mem::take(&mut current));
+                section = Section::Outside;
+            }
+            Section::Theirs => current.theirs.push(line.to_string()),
+        }
+    }
+    hunks
 }
 
 /// Helper to spawn merge-ralph
@@ -1075,6 +1644,7 @@ mod tests {
             ListArgs {
                 json: true,
                 all: true,
+                stall_minutes: 10,
             },
             false,
         )
@@ -1188,6 +1758,7 @@ mod tests {
             ListArgs {
                 json: false,
                 all: false,
+                stall_minutes: 10,
             },
             false,
         )
@@ -1317,6 +1888,71 @@ mod tests {
         assert!(registry.get("loop-discard-1").unwrap().is_none());
     }
 
+    #[cfg(unix)]
+    #[test]
+    fn test_terminate_process_escalates_to_sigkill() {
+        // Ignore SIGTERM so only SIGKILL can stop the process
+        let mut child = Command::new("sh")
+            .args(["-c", "trap '' TERM; while :; do sleep 1; done"])
+            .spawn()
+            .expect("spawn sh");
+        let pid = child.id();
+        // Reap in the background so the exited process doesn't linger as a zombie
+        let waiter = std::thread::spawn(move || child.wait());
+        std::thread::sleep(std::time::Duration::from_millis(200));
+
+        let killed =
+            terminate_process(pid, std::time::Duration::from_millis(300)).expect("terminate");
+
+        assert!(killed);
+        assert!(!waiter.join().unwrap().unwrap().success());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_terminate_process_returns_after_graceful_exit() {
+        let mut child = Command::new("sleep")
+            .arg("30")
+            .spawn()
+            .expect("spawn sleep");
+        let pid = child.id();
+        let waiter = std::thread::spawn(move || child.wait());
+
+        let killed = terminate_process(pid, std::time::Duration::from_secs(5)).expect("terminate");
+
+        assert!(!killed);
+        waiter.join().unwrap().unwrap();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_process_exited_only_after_exit() {
+        let mut child = Command::new("true").spawn().expect("spawn true");
+        let pid = nix::unistd::Pid::from_raw(child.id() as i32);
+        child.wait().expect("wait");
+
+        assert!(process_exited(pid).expect("check exited child"));
+        assert!(!process_exited(nix::unistd::Pid::this()).expect("check self"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_forget_stopped_loop_deregisters() {
+        let temp_dir = tempfile::tempdir().expect("temp dir");
+        let registry = LoopRegistry::new(temp_dir.path());
+        let entry = LoopEntry::with_id(
+            "loop-stopped-1",
+            "stopped",
+            Some(temp_dir.path().join("wt").display().to_string()),
+            temp_dir.path().display().to_string(),
+        );
+        registry.register(entry).expect("register loop");
+
+        forget_stopped_loop(temp_dir.path(), "loop-stopped-1", None);
+
+        assert!(registry.get("loop-stopped-1").unwrap().is_none());
+    }
+
     #[cfg(unix)]
     #[test]
     fn test_stop_loop_writes_stop_requested_file() {
@@ -1328,6 +1964,7 @@ mod tests {
         stop_loop(StopArgs {
             loop_id: None,
             force: false,
+            kill_after: None,
         })
         .expect("stop loop");
 
@@ -1356,6 +1993,234 @@ mod tests {
         assert!(err.to_string().contains("not a worktree-based loop"));
     }
 
+    #[test]
+    fn test_parse_numstat_handles_binary_files() {
+        let files = parse_numstat("3\t1\tsrc/lib.rs\n-\t-\tlogo.png\n");
+        assert_eq!(
+            files,
+            vec![
+                DiffFile {
+                    path: "src/lib.rs".to_string(),
+                    additions: Some(3),
+                    deletions: Some(1),
+                },
+                DiffFile {
+                    path: "logo.png".to_string(),
+                    additions: None,
+                    deletions: None,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_csv_field_quotes_when_needed() {
+        assert_eq!(csv_field("plain"), "plain");
+        assert_eq!(csv_field("a, b"), "\"a, b\"");
+        assert_eq!(csv_field("say \"hi\"\nnow"), "\"say \"\"hi\"\"\nnow\"");
+    }
+
+    #[test]
+    fn test_loop_export_rows_include_timestamps_and_diff_size() {
+        if Command::new("git").arg("--version").output().is_err() {
+            return;
+        }
+
+        let temp_dir = tempfile::tempdir().expect("temp dir");
+        let git = |args: &[&str]| {
+            let status = Command::new("git")
+                .args(args)
+                .current_dir(temp_dir.path())
+                .status()
+                .expect("git");
+            assert!(status.success(), "git {args:?} failed");
+        };
+
+        git(&["init", "-q", "-b", "main"]);
+        git(&["config", "user.email", "test@example.com"]);
+        git(&["config", "user.name", "Test User"]);
+        std::fs::write(temp_dir.path().join("README.md"), "# Test\n").expect("write README");
+        git(&["add", "."]);
+        git(&["commit", "-q", "-m", "Initial commit"]);
+        git(&["checkout", "-q", "-b", "ralph/loop-open"]);
+        std::fs::write(temp_dir.path().join("README.md"), "# Changed\nMore\n")
+            .expect("write README");
+        git(&["commit", "-q", "-am", "Change README"]);
+        git(&["checkout", "-q", "main"]);
+
+        let queue = MergeQueue::new(temp_dir.path());
+        queue
+            .enqueue("loop-open", "Fix the README, please")
+            .unwrap();
+        queue
+            .enqueue("loop-gone", "Branch already deleted")
+            .unwrap();
+        queue.mark_merging("loop-gone", 1).unwrap();
+        queue.mark_needs_review("loop-gone", "conflicts").unwrap();
+        queue.discard("loop-gone", None).unwrap();
+
+        let rows = loop_export_rows(temp_dir.path()).expect("export rows");
+        assert_eq!(rows.len(), 2);
+
+        let open = &rows[0];
+        assert_eq!(open.loop_id, "loop-open");
+        assert_eq!(open.state, "queued");
+        assert_eq!(open.files_changed, Some(1));
+        assert_eq!(open.additions, Some(2));
+        assert_eq!(open.deletions, Some(1));
+        assert!(open.merge_started_at.is_none());
+        assert!(open.to_csv().ends_with(",1,2,1,\"Fix the README, please\""));
+
+        let gone = &rows[1];
+        assert_eq!(gone.state, "discarded");
+        assert!(gone.merge_started_at.is_some());
+        assert!(gone.resolved_at.is_some());
+        assert!(gone.merge_duration_secs.is_none());
+        assert!(gone.files_changed.is_none());
+    }
+
+    #[test]
+    fn test_diff_summary_reports_files_and_truncates_patch() {
+        if Command::new("git").arg("--version").output().is_err() {
+            return;
+        }
+
+        let temp_dir = tempfile::tempdir().expect("temp dir");
+        let git = |args: &[&str]| {
+            let status = Command::new("git")
+                .args(args)
+                .current_dir(temp_dir.path())
+                .status()
+                .expect("git");
+            assert!(status.success(), "git {args:?} failed");
+        };
+
+        git(&["init", "-q", "-b", "main"]);
+        git(&["config", "user.email", "test@example.com"]);
+        git(&["config", "user.name", "Test User"]);
+        std::fs::write(temp_dir.path().join("README.md"), "# Test\n").expect("write README");
+        git(&["add", "."]);
+        git(&["commit", "-q", "-m", "Initial commit"]);
+        git(&["checkout", "-q", "-b", "ralph/loop-diff"]);
+        std::fs::write(temp_dir.path().join("README.md"), "# Changed\nMore\n")
+            .expect("write README");
+        git(&["commit", "-q", "-am", "Change README"]);
+
+        let summary = diff_summary(temp_dir.path(), "loop-diff", "main...ralph/loop-diff", 10)
+            .expect("diff summary");
+
+        assert_eq!(summary.files.len(), 1);
+        assert_eq!(summary.files[0].path, "README.md");
+        assert_eq!((summary.additions, summary.deletions), (2, 1));
+        assert!(summary.patch_truncated);
+        assert_eq!(summary.patch.as_deref().map(str::len), Some(10));
+
+        let no_patch = diff_summary(temp_dir.path(), "loop-diff", "main...ralph/loop-diff", 0)
+            .expect("diff summary");
+        assert!(no_patch.patch.is_none());
+    }
+
+    #[test]
+    fn test_parse_merge_tree_conflicts_and_messages() {
+        let output = "c63571c\nsrc/lib.rs\nREADME.md\n\nAuto-merging README.md\nCONFLICT (content): Merge conflict in README.md\n";
+        let (tree, conflicts, messages) = parse_merge_tree(output);
+        assert_eq!(tree, "c63571c");
+        assert_eq!(conflicts, vec!["src/lib.rs", "README.md"]);
+        assert_eq!(
+            messages,
+            vec![
+                "Auto-merging README.md",
+                "CONFLICT (content): Merge conflict in README.md"
+            ]
+        );
+
+        let (_, conflicts, messages) = parse_merge_tree("c63571c\n");
+        assert!(conflicts.is_empty());
+        assert!(messages.is_empty());
+    }
+
+    #[test]
+    fn test_merge_preview_detects_conflicts() {
+        if Command::new("git").arg("--version").output().is_err() {
+            return;
+        }
+
+        let temp_dir = tempfile::tempdir().expect("temp dir");
+        let git = |args: &[&str]| {
+            let status = Command::new("git")
+                .args(args)
+                .current_dir(temp_dir.path())
+                .status()
+                .expect("git");
+            assert!(status.success(), "git {args:?} failed");
+        };
+        let write = |content: &str| {
+            std::fs::write(temp_dir.path().join("README.md"), content).expect("write README");
+        };
+
+        git(&["init", "-q", "-b", "main"]);
+        git(&["config", "user.email", "test@example.com"]);
+        git(&["config", "user.name", "Test User"]);
+        write("base\n");
+        git(&["add", "."]);
+        git(&["commit", "-q", "-m", "Initial commit"]);
+        git(&["checkout", "-q", "-b", "ralph/loop-merge"]);
+        write("loop\n");
+        git(&["commit", "-q", "-am", "Loop change"]);
+        git(&["checkout", "-q", "main"]);
+
+        let preview = merge_preview(temp_dir.path(), "loop-merge").expect("preview");
+        assert!(preview.clean);
+        assert!(preview.conflicts.is_empty());
+
+        write("main\n");
+        git(&["commit", "-q", "-am", "Main change"]);
+
+        let preview = merge_preview(temp_dir.path(), "loop-merge").expect("preview");
+        assert!(!preview.clean);
+        assert_eq!(
+            preview.conflicts,
+            vec![ConflictFile {
+                path: "README.md".to_string(),
+                hunks: vec![ConflictHunk {
+                    line: 1,
+                    ours: vec!["main".to_string()],
+                    theirs: vec!["loop".to_string()],
+                }],
+            }]
+        );
+    }
+
+    #[test]
+    fn test_parse_conflict_hunks() {
+        let content = "\
+keep
+<<<<<<< main
+ours 1
+ours 2
+||||||| base
+base
+=======
+theirs
+>>>>>>> ralph/loop
+middle
+<<<<<<< main
+=======
+added
+>>>>>>> ralph/loop
+";
+        let hunks = parse_conflict_hunks(content);
+        assert_eq!(hunks.len(), 2);
+        assert_eq!(hunks[0].line, 2);
+        assert_eq!(hunks[0].ours, vec!["ours 1", "ours 2"]);
+        assert_eq!(hunks[0].theirs, vec!["theirs"]);
+        assert_eq!(hunks[1].line, 11);
+        assert!(hunks[1].ours.is_empty());
+        assert_eq!(hunks[1].theirs, vec!["added"]);
+
+        assert!(parse_conflict_hunks("no conflicts\n").is_empty());
+    }
+
     #[test]
     fn test_show_diff_missing_branch_errors() {
         if Command::new("git").arg("--version").output().is_err() {
@@ -1399,6 +2264,8 @@ mod tests {
         let err = show_diff(DiffArgs {
             loop_id: "loop-missing-branch".to_string(),
             stat: false,
+            json: false,
+            max_patch_bytes: DEFAULT_DIFF_PATCH_BYTES,
         })
         .expect_err("missing branch should error");
 
@@ -1444,6 +2311,8 @@ mod tests {
         let err = merge_loop(MergeArgs {
             loop_id: "loop-merged-1".to_string(),
             force: false,
+            dry_run: false,
+            json: false,
         })
         .expect_err("merge should fail for merged loop");
 
@@ -1466,6 +2335,8 @@ mod tests {
         let err = merge_loop(MergeArgs {
             loop_id: "loop-discarded-1".to_string(),
             force: false,
+            dry_run: false,
+            json: false,
         })
         .expect_err("merge should fail for discarded loop");
 
@@ -1486,6 +2357,8 @@ mod tests {
         let err = merge_loop(MergeArgs {
             loop_id: "loop-merging-1".to_string(),
             force: false,
+            dry_run: false,
+            json: false,
         })
         .expect_err("merge should fail for merging loop without force");
 
@@ -21,6 +21,7 @@ mod interact;
 mod loop_runner;
 mod loops;
 mod memory;
+mod migrate;
 mod preflight;
 mod presets;
 mod skill_cli;
@@ -35,10 +36,11 @@ use anyhow::{Context, Result};
 use clap::{ArgAction, Parser, Subcommand, ValueEnum};
 use ralph_adapters::detect_backend;
 use ralph_core::{
-    CheckStatus, EventHistory, LockError, LoopContext, LoopEntry, LoopLock, LoopRegistry,
-    PreflightReport, PreflightRunner, RalphConfig, TerminationReason,
+    CheckStatus, EventHistory, HatAnalytics, LockError, LoopContext, LoopEntry, LoopLock,
+    LoopRegistry, PreflightReport, PreflightRunner, RalphConfig, TerminationReason,
     worktree::{WorktreeConfig, create_worktree, ensure_gitignore, remove_worktree},
 };
+use ralph_proto::Topic;
 use std::fs;
 use std::io::{IsTerminal, Write, stdout};
 use std::path::{Path, PathBuf};
@@ -417,6 +419,9 @@ enum Commands {
     /// Emit an event to the current run's events file with proper JSON formatting
     Emit(EmitArgs),
 
+    /// Migrate a legacy .agent/ workspace to the .ralph/ layout
+    Migrate(migrate::MigrateArgs),
+
     /// Start a Prompt-Driven Development planning session
     Plan(PlanArgs),
 
@@ -598,14 +603,39 @@ struct EventsArgs {
     #[arg(long)]
     last: Option<usize>,
 
-    /// Filter by topic (e.g., "build.blocked")
-    #[arg(long)]
-    topic: Option<String>,
+    /// Filter by topic; accepts comma-separated glob patterns (e.g., "build.blocked" or "iteration.*,human.*")
+    #[arg(long, value_delimiter = ',')]
+    topic: Vec<String>,
+
+    /// Hide topics matching these comma-separated glob patterns
+    #[arg(long, value_delimiter = ',')]
+    exclude: Vec<String>,
 
     /// Filter by iteration number
     #[arg(long)]
     iteration: Option<u32>,
 
+    /// Only show events at or after this time (RFC 3339, e.g. 2025-01-15T10:00:00Z)
+    #[arg(long)]
+    since: Option<chrono::DateTime<chrono::Utc>>,
+
+    /// Only show events before this time (RFC 3339)
+    #[arg(long)]
+    until: Option<chrono::DateTime<chrono::Utc>>,
+
+    /// Skip the first N matching events (for paging through long runs).
+    /// With --format json, paged output is an object with "total", "offset" and "events"
+    #[arg(long, conflicts_with = "last")]
+    offset: Option<usize>,
+
+    /// Show at most N matching events, starting at --offset
+    #[arg(long, conflicts_with = "last")]
+    limit: Option<usize>,
+
+    /// Summarize time spent per hat and hat-to-hat transitions instead of listing events
+    #[arg(long, conflicts_with_all = ["offset", "limit"])]
+    hats: bool,
+
     /// Output format
     #[arg(long, value_enum, default_value_t = OutputFormat::Table)]
     format: OutputFormat,
@@ -629,13 +659,27 @@ struct CleanArgs {
     /// Clean diagnostic logs instead of .agent directory
     #[arg(long)]
     diagnostics: bool,
+
+    /// Archive inactive event logs into .ralph/archives/ and apply the retention policy
+    #[arg(long, conflicts_with = "diagnostics")]
+    archive: bool,
+
+    /// Also archive the current run's event log, with its scratchpad, summary
+    /// and handoff notes (refused while a loop is running)
+    #[arg(long, requires = "archive")]
+    include_current: bool,
+
+    /// List event log archives in .ralph/archives/ without changing anything
+    #[arg(long, conflicts_with_all = ["diagnostics", "archive", "dry_run"])]
+    list_archives: bool,
 }
 
 /// Arguments for the emit subcommand.
 #[derive(Parser, Debug)]
 struct EmitArgs {
     /// Event topic (e.g., "build.done", "review.complete")
-    pub topic: String,
+    #[arg(required_unless_present = "batch")]
+    pub topic: Option<String>,
 
     /// Event payload - string or JSON (optional, defaults to empty)
     #[arg(default_value = "")]
@@ -652,6 +696,20 @@ struct EmitArgs {
     /// Path to events file (defaults to .ralph/events.jsonl)
     #[arg(long, default_value = ".ralph/events.jsonl")]
     pub file: PathBuf,
+
+    /// Emit a JSON array of events from a file ("-" for stdin), written atomically
+    #[arg(long, conflicts_with_all = ["topic", "payload", "json", "ts"])]
+    pub batch: Option<PathBuf>,
+}
+
+/// An event in a `ralph emit --batch` input array.
+#[derive(Debug, serde::Deserialize)]
+struct BatchEvent {
+    topic: String,
+    #[serde(default)]
+    payload: Option<serde_json::Value>,
+    #[serde(default)]
+    ts: Option<String>,
 }
 
 /// Arguments for the tutorial subcommand.
@@ -820,6 +878,7 @@ async fn main() -> Result<()> {
         Some(Commands::Init(args)) => init_command(cli.color, args),
         Some(Commands::Clean(args)) => clean_command(&config_sources, cli.color, args),
         Some(Commands::Emit(args)) => emit_command(cli.color, args),
+        Some(Commands::Migrate(args)) => migrate::execute(args, cli.color.should_use_colors()),
         Some(Commands::Plan(args)) => plan_command(&config_sources, cli.color, args),
         Some(Commands::CodeTask(args)) => code_task_command(&config_sources, cli.color, args),
         Some(Commands::Task(args)) => code_task_command(&config_sources, cli.color, args),
@@ -1050,8 +1109,10 @@ async fn run_command(
                         available
                     )
                 })?;
-                RalphConfig::parse_yaml(preset.content)
-                    .with_context(|| format!("Failed to parse builtin preset '{}'", name))?
+                let mut config = RalphConfig::parse_yaml(preset.content)
+                    .with_context(|| format!("Failed to parse builtin preset '{}'", name))?;
+                config.core.preset = Some(name.clone());
+                config
             }
             ConfigSource::Remote(url) => {
                 info!("Fetching config from {}", url);
@@ -1305,6 +1366,15 @@ async fn run_command(
                     existing.pid,
                     existing.prompt.chars().take(50).collect::<String>()
                 );
+            } else if let Some(max) = config.features.max_parallel_loops
+                && running_worktree_loops(workspace_root) >= max
+            {
+                anyhow::bail!(
+                    "Another loop is already running (PID {}), and the parallel loop limit \
+                    is reached (features.max_parallel_loops: {max}). \
+                    Use --exclusive to wait for the lock, or stop a loop with `ralph loops stop`.",
+                    existing.pid
+                );
             } else {
                 // Auto-spawn into worktree
                 info!(
@@ -1721,14 +1791,20 @@ fn events_command(color_mode: ColorMode, args: EventsArgs) -> Result<()> {
     let mut records = history.read_all()?;
 
     // Apply filters in sequence
-    if let Some(ref topic) = args.topic {
-        records.retain(|r| r.topic == *topic);
+    if !args.topic.is_empty() || !args.exclude.is_empty() {
+        let include: Vec<Topic> = args.topic.iter().map(Topic::new).collect();
+        let exclude: Vec<Topic> = args.exclude.iter().map(Topic::new).collect();
+        records.retain(|r| topic_selected(&r.topic, &include, &exclude));
     }
 
     if let Some(iteration) = args.iteration {
         records.retain(|r| r.iteration == iteration);
     }
 
+    if args.since.is_some() || args.until.is_some() {
+        records.retain(|r| event_in_time_range(&r.ts, args.since, args.until));
+    }
+
     // Apply 'last' filter after other filters (to get last N of filtered results)
     if let Some(n) = args.last
         && records.len() > n
@@ -1736,7 +1812,21 @@ fn events_command(color_mode: ColorMode, args: EventsArgs) -> Result<()> {
         records = records.into_iter().rev().take(n).rev().collect();
     }
 
-    if records.is_empty() {
+    // Page through filtered results; the total is reported so callers know when to stop
+    let total = records.len();
+    let offset = args.offset.unwrap_or(0);
+    let paginated = args.offset.is_some() || args.limit.is_some();
+    if paginated {
+        records = records
+            .into_iter()
+            .skip(offset)
+            .take(args.limit.unwrap_or(usize::MAX))
+            .collect();
+    }
+
+    // An empty page is still valid JSON output for paging clients
+    let json_page = paginated && matches!(args.format, OutputFormat::Json);
+    if records.is_empty() && !json_page {
         if use_colors {
             println!("{}No matching events found.{}", colors::DIM, colors::RESET);
         } else {
@@ -1745,19 +1835,73 @@ fn events_command(color_mode: ColorMode, args: EventsArgs) -> Result<()> {
         return Ok(());
     }
 
+    if args.hats {
+        let analytics = HatAnalytics::from_records(&records);
+        match args.format {
+            OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&analytics)?),
+            OutputFormat::Table => display::print_hat_analytics(&analytics, use_colors),
+        }
+        return Ok(());
+    }
+
     match args.format {
         OutputFormat::Json => {
-            let json = serde_json::to_string_pretty(&records)?;
+            // When paging, wrap the page so callers can tell when to stop
+            let json = if paginated {
+                serde_json::to_string_pretty(&serde_json::json!({
+                    "total": total,
+                    "offset": offset,
+                    "events": records,
+                }))?
+            } else {
+                serde_json::to_string_pretty(&records)?
+            };
             println!("{json}");
         }
         OutputFormat::Table => {
             display::print_events_table(&records, use_colors);
+            if paginated {
+                println!(
+                    "\nShowing events {}-{} of {total}",
+                    offset + 1,
+                    offset + records.len()
+                );
+            }
         }
     }
 
     Ok(())
 }
 
+/// Returns true if `topic` matches any include pattern (or there are none)
+/// and no exclude pattern.
+fn topic_selected(topic: &str, include: &[Topic], exclude: &[Topic]) -> bool {
+    (include.is_empty() || include.iter().any(|p| p.matches_str(topic)))
+        && !exclude.iter().any(|p| p.matches_str(topic))
+}
+
+/// Counts registered worktree loops whose process is still running.
+fn running_worktree_loops(workspace_root: &Path) -> usize {
+    LoopRegistry::new(workspace_root)
+        .list()
+        .map(|entries| entries.iter().filter(|e| e.is_alive()).count())
+        .unwrap_or(0)
+}
+
+/// Returns true if an event timestamp falls within `[since, until)`.
+///
+/// Events with unparseable timestamps are excluded once a bound is given.
+fn event_in_time_range(
+    ts: &str,
+    since: Option<chrono::DateTime<chrono::Utc>>,
+    until: Option<chrono::DateTime<chrono::Utc>>,
+) -> bool {
+    let Ok(ts) = chrono::DateTime::parse_from_rfc3339(ts) else {
+        return false;
+    };
+    since.is_none_or(|since| ts >= since) && until.is_none_or(|until| ts < until)
+}
+
 fn clean_command(
     config_sources: &[ConfigSource],
     color_mode: ColorMode,
@@ -1774,6 +1918,14 @@ fn clean_command(
     // Load config with overrides applied
     let config = load_config_with_overrides(config_sources)?;
 
+    if args.archive {
+        return archive_event_logs(&config, use_colors, args.dry_run, args.include_current);
+    }
+
+    if args.list_archives {
+        return list_event_archives(&config);
+    }
+
     // Extract the .agent directory path from scratchpad path
     let scratchpad_path = Path::new(&config.core.scratchpad);
     let agent_dir = scratchpad_path.parent().ok_or_else(|| {
@@ -1847,6 +1999,107 @@ fn clean_command(
     Ok(())
 }
 
+/// Archives inactive event logs and prunes archives outside the retention policy.
+fn archive_event_logs(
+    config: &RalphConfig,
+    use_colors: bool,
+    dry_run: bool,
+    include_current: bool,
+) -> Result<()> {
+    let ralph_dir = config.core.workspace_root.join(".ralph");
+    let mut logs = ralph_core::inactive_event_logs(&ralph_dir)
+        .with_context(|| format!("Failed to scan '{}'", ralph_dir.display()))?;
+
+    if include_current && let Some(current) = ralph_core::current_event_log(&ralph_dir) {
+        if LoopLock::is_locked(&config.core.workspace_root)
+            .context("Failed to check the loop lock")?
+        {
+            anyhow::bail!(
+                "A loop is running; refusing to archive its event log '{}'",
+                current.display()
+            );
+        }
+        logs.push(current);
+    }
+
+    if dry_run {
+        if logs.is_empty() {
+            println!("Dry run mode: No inactive event logs to archive");
+        } else {
+            println!("Dry run mode: Would archive {} event log(s):", logs.len());
+            for log in &logs {
+                println!("  {}", log.display());
+            }
+        }
+
+        let pruned = ralph_core::archives_outside_retention(
+            &ralph_dir,
+            &config.features.archive,
+            chrono::Utc::now(),
+            logs.len(),
+        )
+        .context("Failed to apply archive retention policy")?;
+        if !pruned.is_empty() {
+            println!("Dry run mode: Would prune {} archive(s):", pruned.len());
+            for archive in &pruned {
+                println!("  {}", archive.path.display());
+            }
+        }
+        return Ok(());
+    }
+
+    for log in &logs {
+        let archive = ralph_core::archive_event_log(&ralph_dir, log)
+            .with_context(|| format!("Failed to archive '{}'", log.display()))?;
+        if use_colors {
+            println!(
+                "{}✓{} Archived {} → {}",
+                colors::GREEN,
+                colors::RESET,
+                log.display(),
+                archive.display()
+            );
+        } else {
+            println!("Archived {} -> {}", log.display(), archive.display());
+        }
+    }
+
+    let removed =
+        ralph_core::enforce_retention(&ralph_dir, &config.features.archive, chrono::Utc::now())
+            .context("Failed to apply archive retention policy")?;
+    for path in &removed {
+        println!("Pruned {}", path.display());
+    }
+
+    if logs.is_empty() && removed.is_empty() {
+        println!("Nothing to archive");
+    }
+    Ok(())
+}
+
+/// Lists event log archives, newest first.
+fn list_event_archives(config: &RalphConfig) -> Result<()> {
+    let ralph_dir = config.core.workspace_root.join(".ralph");
+    let archives = ralph_core::list_archives(&ralph_dir)
+        .with_context(|| format!("Failed to list archives in '{}'", ralph_dir.display()))?;
+
+    if archives.is_empty() {
+        println!("No event log archives found");
+        return Ok(());
+    }
+
+    for archive in &archives {
+        println!(
+            "{}  {:>10} bytes  {}",
+            archive.created_at.format("%Y-%m-%d %H:%M:%S"),
+            archive.size_bytes,
+            archive.path.display()
+        );
+    }
+    println!("\nTotal: {} archive(s)", archives.len());
+    Ok(())
+}
+
 /// Emit an event to the current run's events file with proper JSON formatting.
 ///
 /// This command provides a deterministic way for agents to emit events without
@@ -1858,6 +2111,11 @@ fn clean_command(
 fn emit_command(color_mode: ColorMode, args: EmitArgs) -> Result<()> {
     let use_colors = color_mode.should_use_colors();
 
+    if let Some(batch) = &args.batch {
+        return emit_batch(use_colors, batch, &args.file);
+    }
+    let topic = args.topic.unwrap_or_default();
+
     // Generate timestamp if not provided
     let ts = args.ts.unwrap_or_else(|| chrono::Utc::now().to_rfc3339());
 
@@ -1873,7 +2131,7 @@ fn emit_command(color_mode: ColorMode, args: EmitArgs) -> Result<()> {
     // Build the event record
     // We use serde_json directly to ensure proper escaping
     let record = serde_json::json!({
-        "topic": args.topic,
+        "topic": topic,
         "payload": if args.json && !payload.is_empty() {
             // Parse and embed as object
             serde_json::from_str::<serde_json::Value>(&payload)?
@@ -1885,19 +2143,7 @@ fn emit_command(color_mode: ColorMode, args: EmitArgs) -> Result<()> {
         "ts": ts
     });
 
-    // Read events path from marker file, fall back to CLI arg if marker doesn't exist
-    // This ensures `ralph emit` writes to the same events file as the active run
-    let events_file = fs::read_to_string(".ralph/current-events")
-        .map(|s| PathBuf::from(s.trim()))
-        .unwrap_or_else(|_| args.file.clone());
-
-    // Ensure parent directory exists
-    if let Some(parent) = events_file.parent()
-        && !parent.as_os_str().is_empty()
-    {
-        fs::create_dir_all(parent)
-            .with_context(|| format!("Failed to create directory: {}", parent.display()))?;
-    }
+    let events_file = resolve_emit_events_file(&args.file)?;
 
     // Append to file
     let mut file = fs::OpenOptions::new()
@@ -1916,10 +2162,79 @@ fn emit_command(color_mode: ColorMode, args: EmitArgs) -> Result<()> {
             "{}✓{} Event emitted: {}",
             colors::GREEN,
             colors::RESET,
-            args.topic
+            topic
+        );
+    } else {
+        println!("Event emitted: {}", topic);
+    }
+
+    Ok(())
+}
+
+/// Resolves the events file for `ralph emit`.
+///
+/// Reads the path from the `.ralph/current-events` marker so emitted events land
+/// in the active run's file, falling back to the `--file` argument.
+fn resolve_emit_events_file(fallback: &Path) -> Result<PathBuf> {
+    let events_file = fs::read_to_string(".ralph/current-events")
+        .map(|s| PathBuf::from(s.trim()))
+        .unwrap_or_else(|_| fallback.to_path_buf());
+
+    if let Some(parent) = events_file.parent()
+        && !parent.as_os_str().is_empty()
+    {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create directory: {}", parent.display()))?;
+    }
+
+    Ok(events_file)
+}
+
+/// Parses a `ralph emit --batch` input array into events, defaulting missing timestamps.
+fn parse_batch_events(input: &str) -> Result<Vec<ralph_core::Event>> {
+    let batch: Vec<BatchEvent> =
+        serde_json::from_str(input).context("Batch input must be a JSON array of events")?;
+    let now = chrono::Utc::now().to_rfc3339();
+
+    Ok(batch
+        .into_iter()
+        .map(|event| ralph_core::Event {
+            topic: event.topic,
+            payload: match event.payload {
+                None | Some(serde_json::Value::Null) => None,
+                Some(serde_json::Value::String(s)) => Some(s),
+                Some(value) => Some(value.to_string()),
+            },
+            ts: event.ts.unwrap_or_else(|| now.clone()),
+        })
+        .collect())
+}
+
+fn emit_batch(use_colors: bool, batch: &Path, fallback_file: &Path) -> Result<()> {
+    let input = if batch.as_os_str() == "-" {
+        let mut buf = String::new();
+        std::io::Read::read_to_string(&mut std::io::stdin(), &mut buf)
+            .context("Failed to read events from stdin")?;
+        buf
+    } else {
+        fs::read_to_string(batch)
+            .with_context(|| format!("Failed to read batch file: {}", batch.display()))?
+    };
+    let events = parse_batch_events(&input)?;
+
+    let events_file = resolve_emit_events_file(fallback_file)?;
+    ralph_core::append_events(&events_file, &events)
+        .with_context(|| format!("Failed to emit batch to {}", events_file.display()))?;
+
+    if use_colors {
+        println!(
+            "{}✓{} Emitted {} event(s)",
+            colors::GREEN,
+            colors::RESET,
+            events.len()
         );
     } else {
-        println!("Event emitted: {}", args.topic);
+        println!("Emitted {} event(s)", events.len());
     }
 
     Ok(())
@@ -2211,6 +2526,61 @@ mod tests {
     use crate::test_support::CwdGuard;
     use std::path::PathBuf;
 
+    #[test]
+    fn test_topic_selected_with_globs_and_excludes() {
+        let include = vec![Topic::new("iteration.*"), Topic::new("human.*")];
+        let exclude = vec![Topic::new("human.guidance")];
+
+        assert!(topic_selected("iteration.started", &include, &[]));
+        assert!(topic_selected("human.response", &include, &exclude));
+        assert!(!topic_selected("human.guidance", &include, &exclude));
+        assert!(!topic_selected("build.done", &include, &exclude));
+        assert!(topic_selected("build.done", &[], &exclude));
+        assert!(topic_selected(
+            "build.blocked",
+            &[Topic::new("build.blocked")],
+            &[]
+        ));
+    }
+
+    #[test]
+    fn test_running_worktree_loops_counts_live_entries() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        assert_eq!(running_worktree_loops(temp_dir.path()), 0);
+
+        let registry = LoopRegistry::new(temp_dir.path());
+        let mut dead = LoopEntry::with_id("loop-dead", "old", Some("/tmp/wt-dead"), "/tmp");
+        dead.pid = 999_999_999;
+        registry.register(dead).unwrap();
+        registry
+            .register(LoopEntry::with_id(
+                "loop-live",
+                "current",
+                Some("/tmp/wt-live"),
+                "/tmp",
+            ))
+            .unwrap();
+
+        assert_eq!(running_worktree_loops(temp_dir.path()), 1);
+    }
+
+    #[test]
+    fn test_event_in_time_range() {
+        let since = "2025-01-15T10:00:00Z".parse().ok();
+        let until = "2025-01-15T11:00:00Z".parse().ok();
+
+        assert!(event_in_time_range("2025-01-15T10:00:00Z", since, until));
+        assert!(event_in_time_range(
+            "2025-01-15T12:30:00+02:00",
+            since,
+            until
+        ));
+        assert!(!event_in_time_range("2025-01-15T11:00:00Z", since, until));
+        assert!(!event_in_time_range("2025-01-15T09:59:59Z", since, None));
+        assert!(event_in_time_range("2025-01-15T09:59:59Z", None, until));
+        assert!(!event_in_time_range("not a timestamp", since, None));
+    }
+
     #[test]
     fn test_verbosity_cli_quiet() {
         assert_eq!(Verbosity::resolve(false, true), Verbosity::Quiet);
@@ -2336,6 +2706,49 @@ mod tests {
         assert!(matches!(cli.command, Some(Commands::Tutorial(_))));
     }
 
+    #[test]
+    fn test_emit_batch_parses_without_topic() {
+        let cli = Cli::try_parse_from(["ralph", "emit", "--batch", "events.json"]).unwrap();
+        match cli.command {
+            Some(Commands::Emit(args)) => {
+                assert!(args.topic.is_none());
+                assert_eq!(args.batch, Some(PathBuf::from("events.json")));
+            }
+            _ => panic!("expected emit command"),
+        }
+
+        assert!(Cli::try_parse_from(["ralph", "emit"]).is_err());
+    }
+
+    #[test]
+    fn test_events_hats_rejects_paging() {
+        // Hat analytics summarize the whole filtered log, not a page of it
+        assert!(Cli::try_parse_from(["ralph", "events", "--hats", "--last", "50"]).is_ok());
+        assert!(Cli::try_parse_from(["ralph", "events", "--hats", "--offset", "10"]).is_err());
+        assert!(Cli::try_parse_from(["ralph", "events", "--hats", "--limit", "10"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_batch_events_normalizes_payloads() {
+        let events = parse_batch_events(
+            r#"[
+                {"topic": "deploy.finished", "payload": {"env": "prod"}, "ts": "2025-01-01T00:00:00Z"},
+                {"topic": "alert.fired", "payload": "disk full"},
+                {"topic": "ci.green"}
+            ]"#,
+        )
+        .unwrap();
+
+        assert_eq!(events.len(), 3);
+        assert_eq!(events[0].payload.as_deref(), Some(r#"{"env":"prod"}"#));
+        assert_eq!(events[0].ts, "2025-01-01T00:00:00Z");
+        assert_eq!(events[1].payload.as_deref(), Some("disk full"));
+        assert!(events[2].payload.is_none());
+        assert!(!events[2].ts.is_empty());
+
+        assert!(parse_batch_events(r#"{"topic": "x"}"#).is_err());
+    }
+
     #[test]
     fn test_tutorial_steps_cover_core_topics() {
         let steps = tutorial_steps();
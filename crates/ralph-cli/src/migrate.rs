@@ -0,0 +1,102 @@
+//! CLI command for `ralph migrate`.
+//!
+//! Converts a legacy `.agent/` workspace to the current `.ralph/` layout.
+//! Use `--dry-run` to print the planned moves without touching any files.
+
+use crate::display::colors;
+use anyhow::{Context, Result};
+use clap::Parser;
+use ralph_core::{MigrationAction, MigrationPlan};
+use std::path::{Path, PathBuf};
+
+/// Arguments for the migrate subcommand.
+#[derive(Parser, Debug)]
+pub struct MigrateArgs {
+    /// Show what would be moved without changing anything
+    #[arg(long)]
+    pub dry_run: bool,
+
+    /// Output the migration report as JSON
+    #[arg(long)]
+    pub json: bool,
+
+    /// Working directory (default: current directory)
+    #[arg(long)]
+    pub root: Option<PathBuf>,
+}
+
+/// Executes the migrate command.
+pub fn execute(args: MigrateArgs, use_colors: bool) -> Result<()> {
+    let root = args.root.as_deref().unwrap_or(Path::new("."));
+    let plan =
+        MigrationPlan::for_workspace(root).context("Failed to scan legacy .agent directory")?;
+
+    if args.json {
+        println!("{}", serde_json::to_string_pretty(&plan)?);
+    } else if plan.steps.is_empty() {
+        println!("Nothing to migrate: no legacy .agent/ directory found");
+        return Ok(());
+    } else {
+        print_plan(&plan, root, use_colors);
+    }
+
+    if args.dry_run || plan.is_noop() {
+        return Ok(());
+    }
+
+    let moved = plan.apply().context("Failed to migrate .agent directory")?;
+    if !args.json {
+        if use_colors {
+            println!(
+                "{}✓{} Migrated {} file(s) to .ralph/",
+                colors::GREEN,
+                colors::RESET,
+                moved
+            );
+        } else {
+            println!("Migrated {} file(s) to .ralph/", moved);
+        }
+    }
+
+    Ok(())
+}
+
+fn print_plan(plan: &MigrationPlan, root: &Path, use_colors: bool) {
+    let relative = |p: &Path| p.strip_prefix(root).unwrap_or(p).display().to_string();
+
+    for step in &plan.steps {
+        let (label, color) = match step.action {
+            MigrationAction::Move => ("move", colors::GREEN),
+            MigrationAction::SkipExisting => ("skip", colors::YELLOW),
+        };
+        if use_colors {
+            println!(
+                "{}{:<5}{} {} -> {}",
+                color,
+                label,
+                colors::RESET,
+                relative(&step.from),
+                relative(&step.to)
+            );
+        } else {
+            println!(
+                "{:<5} {} -> {}",
+                label,
+                relative(&step.from),
+                relative(&step.to)
+            );
+        }
+    }
+
+    let skipped = plan
+        .steps
+        .iter()
+        .filter(|s| s.action == MigrationAction::SkipExisting)
+        .count();
+    if skipped > 0 {
+        println!(
+            "{} file(s) already exist in .ralph/ and will be left in .agent/ for manual review",
+            skipped
+        );
+    }
+}
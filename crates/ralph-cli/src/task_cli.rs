@@ -6,12 +6,15 @@
 //! - `ready`: Show unblocked tasks
 //! - `close`: Mark a task as complete
 //! - `show`: Show a single task by ID
+//! - `delete`: Remove a task by ID
+//! - `bulk`: Apply a batch of operations atomically
 
 use crate::display::colors;
 use anyhow::{Context, Result};
 use chrono::{DateTime, Utc};
 use clap::{Parser, Subcommand, ValueEnum};
-use ralph_core::{Task, TaskStatus, TaskStore};
+use ralph_core::{Task, TaskOperation, TaskStatus, TaskStore};
+use std::io::Read;
 use std::path::{Path, PathBuf};
 
 /// Output format for task commands.
@@ -56,6 +59,12 @@ pub enum TaskCommands {
 
     /// Show a single task by ID
     Show(ShowArgs),
+
+    /// Remove a task by ID
+    Delete(DeleteArgs),
+
+    /// Apply a batch of operations (close, fail, reprioritize, reblock, delete) atomically
+    Bulk(BulkArgs),
 }
 
 /// Arguments for the `task add` command.
@@ -142,6 +151,25 @@ pub struct ShowArgs {
     pub format: OutputFormat,
 }
 
+/// Arguments for the `task delete` command.
+#[derive(Parser, Debug)]
+pub struct DeleteArgs {
+    /// Task ID to delete
+    pub id: String,
+}
+
+/// Arguments for the `task bulk` command.
+#[derive(Parser, Debug)]
+pub struct BulkArgs {
+    /// JSON file containing an array of operations (default: read from stdin)
+    #[arg(long)]
+    pub file: Option<PathBuf>,
+
+    /// Output format
+    #[arg(long, value_enum, default_value_t = OutputFormat::Table)]
+    pub format: OutputFormat,
+}
+
 /// Gets the tasks file path.
 fn get_tasks_path(root: Option<&PathBuf>) -> PathBuf {
     let base = root.map(|p| p.as_path()).unwrap_or(Path::new("."));
@@ -263,6 +291,8 @@ pub fn execute(args: TaskArgs, use_colors: bool) -> Result<()> {
         TaskCommands::Close(close_args) => execute_close(close_args, root.as_ref(), use_colors),
         TaskCommands::Fail(fail_args) => execute_fail(fail_args, root.as_ref(), use_colors),
         TaskCommands::Show(show_args) => execute_show(show_args, root.as_ref(), use_colors),
+        TaskCommands::Delete(delete_args) => execute_delete(delete_args, root.as_ref(), use_colors),
+        TaskCommands::Bulk(bulk_args) => execute_bulk(bulk_args, root.as_ref(), use_colors),
     }
 }
 
@@ -538,6 +568,86 @@ fn execute_fail(args: FailArgs, root: Option<&PathBuf>, use_colors: bool) -> Res
     Ok(())
 }
 
+fn execute_delete(args: DeleteArgs, root: Option<&PathBuf>, use_colors: bool) -> Result<()> {
+    let path = get_tasks_path(root);
+    let mut store = TaskStore::load(&path).context("Failed to load tasks")?;
+
+    let removed = store
+        .with_exclusive_lock(|s| s.remove(&args.id))
+        .context("Failed to save tasks")?
+        .context(format!("Task {} not found", args.id))?;
+
+    if use_colors {
+        println!(
+            "{}Deleted task: {} - {}{}",
+            colors::YELLOW,
+            removed.id,
+            removed.title,
+            colors::RESET
+        );
+    } else {
+        println!("Deleted task: {} - {}", removed.id, removed.title);
+    }
+
+    Ok(())
+}
+
+fn parse_bulk_operations(input: &str) -> Result<Vec<TaskOperation>> {
+    serde_json::from_str(input).context("Failed to parse bulk operations (expected a JSON array)")
+}
+
+fn execute_bulk(args: BulkArgs, root: Option<&PathBuf>, use_colors: bool) -> Result<()> {
+    let input = match &args.file {
+        Some(file) => std::fs::read_to_string(file)
+            .with_context(|| format!("Failed to read {}", file.display()))?,
+        None => {
+            let mut buf = String::new();
+            std::io::stdin()
+                .read_to_string(&mut buf)
+                .context("Failed to read operations from stdin")?;
+            buf
+        }
+    };
+    let ops = parse_bulk_operations(&input)?;
+
+    let path = get_tasks_path(root);
+    let mut store = TaskStore::load(&path).context("Failed to load tasks")?;
+    let results = store
+        .apply_bulk(&ops)
+        .context("Failed to apply bulk task operations")?;
+
+    match args.format {
+        OutputFormat::Table => {
+            for result in &results {
+                match (&result.error, use_colors) {
+                    (None, true) => {
+                        println!("{}ok{}     {}", colors::GREEN, colors::RESET, result.id);
+                    }
+                    (None, false) => println!("ok     {}", result.id),
+                    (Some(error), true) => println!(
+                        "{}failed{} {} ({})",
+                        colors::RED,
+                        colors::RESET,
+                        result.id,
+                        error
+                    ),
+                    (Some(error), false) => println!("failed {} ({})", result.id, error),
+                }
+            }
+        }
+        OutputFormat::Json => {
+            println!("{}", serde_json::to_string_pretty(&results)?);
+        }
+        OutputFormat::Quiet => {
+            for result in results.iter().filter(|r| r.ok) {
+                println!("{}", result.id);
+            }
+        }
+    }
+
+    Ok(())
+}
+
 fn execute_show(args: ShowArgs, root: Option<&PathBuf>, use_colors: bool) -> Result<()> {
     let path = get_tasks_path(root);
     let store = TaskStore::load(&path).context("Failed to load tasks")?;
@@ -659,6 +769,18 @@ mod tests {
         assert_eq!(filtered[0].status, TaskStatus::InProgress);
     }
 
+    #[test]
+    fn test_parse_bulk_operations() {
+        let ops = parse_bulk_operations(
+            r#"[{"op": "close", "id": "task-1-aaaa"}, {"op": "reprioritize", "id": "task-2-bbbb", "priority": 1}]"#,
+        )
+        .expect("parse ops");
+        assert_eq!(ops.len(), 2);
+        assert_eq!(ops[1].task_id(), "task-2-bbbb");
+
+        assert!(parse_bulk_operations(r#"{"op": "close"}"#).is_err());
+    }
+
     #[test]
     fn test_ready_filters_by_loop_id_marker() {
         let temp_dir = TempDir::new().expect("temp dir");
@@ -314,3 +314,147 @@ core:
 
     Ok(())
 }
+
+#[test]
+fn test_clean_archive_moves_inactive_event_logs() -> Result<()> {
+    let temp_dir = TempDir::new()?;
+    let temp_path = temp_dir.path();
+
+    let config_content = r"
+features:
+  archive:
+    max_count: 1
+";
+    fs::write(temp_path.join("ralph.yml"), config_content)?;
+
+    let ralph_dir = temp_path.join(".ralph");
+    fs::create_dir_all(ralph_dir.join("agent"))?;
+    fs::write(ralph_dir.join("events-20250101-000000.jsonl"), "{}")?;
+    fs::write(ralph_dir.join("events-20250102-000000.jsonl"), "{}")?;
+    fs::write(
+        ralph_dir.join("current-events"),
+        ".ralph/events-20250102-000000.jsonl",
+    )?;
+    fs::write(ralph_dir.join("agent/scratchpad.md"), "notes")?;
+
+    let output = Command::new(env!("CARGO_BIN_EXE_ralph"))
+        .arg("clean")
+        .arg("--archive")
+        .arg("--config")
+        .arg(temp_path.join("ralph.yml"))
+        .current_dir(temp_path)
+        .output()?;
+
+    assert!(
+        output.status.success(),
+        "Command should succeed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    // The inactive log is archived, the active one is untouched
+    assert!(!ralph_dir.join("events-20250101-000000.jsonl").exists());
+    assert!(ralph_dir.join("events-20250102-000000.jsonl").exists());
+    assert!(
+        ralph_dir
+            .join("archives/events-20250101-000000.tar.gz")
+            .exists()
+    );
+
+    // Agent state belongs to the current run and stays in place
+    assert!(ralph_dir.join("agent/scratchpad.md").exists());
+
+    Ok(())
+}
+
+#[test]
+fn test_clean_archive_include_current_snapshots_agent_state() -> Result<()> {
+    let temp_dir = TempDir::new()?;
+    let temp_path = temp_dir.path();
+    fs::write(temp_path.join("ralph.yml"), "")?;
+
+    let ralph_dir = temp_path.join(".ralph");
+    fs::create_dir_all(ralph_dir.join("agent"))?;
+    fs::write(ralph_dir.join("events-20250102-000000.jsonl"), "{}")?;
+    fs::write(
+        ralph_dir.join("current-events"),
+        ".ralph/events-20250102-000000.jsonl",
+    )?;
+    fs::write(ralph_dir.join("agent/scratchpad.md"), "notes")?;
+
+    let output = Command::new(env!("CARGO_BIN_EXE_ralph"))
+        .arg("clean")
+        .arg("--archive")
+        .arg("--include-current")
+        .arg("--config")
+        .arg(temp_path.join("ralph.yml"))
+        .current_dir(temp_path)
+        .output()?;
+
+    assert!(
+        output.status.success(),
+        "Command should succeed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let archive = ralph_dir.join("archives/events-20250102-000000.tar.gz");
+    assert!(archive.exists());
+    assert!(!ralph_dir.join("events-20250102-000000.jsonl").exists());
+    assert!(!ralph_dir.join("current-events").exists());
+
+    let listing = Command::new("tar").arg("-tzf").arg(&archive).output()?;
+    assert!(String::from_utf8_lossy(&listing.stdout).contains("agent/scratchpad.md"));
+
+    Ok(())
+}
+
+#[test]
+fn test_clean_archive_dry_run_lists_pruned_archives() -> Result<()> {
+    let temp_dir = TempDir::new()?;
+    let temp_path = temp_dir.path();
+
+    let config_content = r"
+features:
+  archive:
+    max_count: 1
+";
+    fs::write(temp_path.join("ralph.yml"), config_content)?;
+
+    let ralph_dir = temp_path.join(".ralph");
+    fs::create_dir_all(ralph_dir.join("archives"))?;
+    fs::write(ralph_dir.join("archives/events-old.tar.gz"), "old")?;
+    fs::write(ralph_dir.join("events-20250101-000000.jsonl"), "{}")?;
+
+    let output = Command::new(env!("CARGO_BIN_EXE_ralph"))
+        .arg("clean")
+        .arg("--archive")
+        .arg("--dry-run")
+        .arg("--config")
+        .arg(temp_path.join("ralph.yml"))
+        .current_dir(temp_path)
+        .output()?;
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("Would archive 1 event log(s)"));
+    assert!(stdout.contains("Would prune 1 archive(s)"));
+    assert!(stdout.contains("events-old.tar.gz"));
+
+    // Dry run leaves everything in place
+    assert!(ralph_dir.join("archives/events-old.tar.gz").exists());
+    assert!(ralph_dir.join("events-20250101-000000.jsonl").exists());
+
+    let output = Command::new(env!("CARGO_BIN_EXE_ralph"))
+        .arg("clean")
+        .arg("--list-archives")
+        .arg("--config")
+        .arg(temp_path.join("ralph.yml"))
+        .current_dir(temp_path)
+        .output()?;
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("events-old.tar.gz"));
+    assert!(stdout.contains("Total: 1 archive(s)"));
+
+    Ok(())
+}
@@ -640,3 +640,41 @@ fn test_ralph_directory_created() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_events_json_paging_reports_total() -> Result<()> {
+    let temp_dir = TempDir::new()?;
+    let events_path = temp_dir.path().join("events.jsonl");
+    fs::write(
+        &events_path,
+        "{\"topic\":\"a\",\"ts\":\"2025-01-15T10:00:00Z\"}\n\
+         {\"topic\":\"b\",\"ts\":\"2025-01-15T10:01:00Z\"}\n\
+         {\"topic\":\"c\",\"ts\":\"2025-01-15T10:02:00Z\"}\n",
+    )?;
+
+    let page = |offset: &str| -> Result<serde_json::Value> {
+        let output = Command::new(ralph_bin())
+            .args([
+                "events", "--format", "json", "--limit", "2", "--offset", offset,
+            ])
+            .arg("--file")
+            .arg(&events_path)
+            .current_dir(temp_dir.path())
+            .output()?;
+        assert!(output.status.success());
+        Ok(serde_json::from_slice(&output.stdout)?)
+    };
+
+    let first = page("1")?;
+    assert_eq!(first["total"], 3);
+    assert_eq!(first["offset"], 1);
+    assert_eq!(first["events"].as_array().unwrap().len(), 2);
+    assert_eq!(first["events"][0]["topic"], "b");
+
+    // Paging past the end still yields a parseable, empty page
+    let past_end = page("3")?;
+    assert_eq!(past_end["total"], 3);
+    assert!(past_end["events"].as_array().unwrap().is_empty());
+
+    Ok(())
+}
@@ -0,0 +1,63 @@
+//! Integration tests for `ralph migrate`.
+
+use std::fs;
+use std::process::Command;
+use tempfile::TempDir;
+
+fn ralph_migrate(temp_path: &std::path::Path, args: &[&str]) -> std::process::Output {
+    Command::new(env!("CARGO_BIN_EXE_ralph"))
+        .arg("migrate")
+        .args(args)
+        .arg("--root")
+        .arg(temp_path)
+        .current_dir(temp_path)
+        .output()
+        .expect("Failed to execute ralph migrate")
+}
+
+fn seed_legacy_workspace(temp_path: &std::path::Path) {
+    let agent_dir = temp_path.join(".agent");
+    fs::create_dir_all(&agent_dir).expect("create .agent");
+    fs::write(agent_dir.join("scratchpad.md"), "# Scratchpad").expect("write scratchpad");
+    fs::write(agent_dir.join("tasks.jsonl"), "").expect("write tasks");
+    fs::write(agent_dir.join("events.jsonl"), "{}\n").expect("write events");
+}
+
+#[test]
+fn test_migrate_dry_run_reports_without_moving() {
+    let temp_dir = TempDir::new().expect("temp dir");
+    let temp_path = temp_dir.path();
+    seed_legacy_workspace(temp_path);
+
+    let output = ralph_migrate(temp_path, &["--dry-run", "--json"]);
+    assert!(
+        output.status.success(),
+        "migrate failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let report: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("parse migration report");
+    assert_eq!(report["steps"].as_array().unwrap().len(), 3);
+    assert!(temp_path.join(".agent/scratchpad.md").exists());
+    assert!(!temp_path.join(".ralph").exists());
+}
+
+#[test]
+fn test_migrate_moves_legacy_layout() {
+    let temp_dir = TempDir::new().expect("temp dir");
+    let temp_path = temp_dir.path();
+    seed_legacy_workspace(temp_path);
+
+    let output = ralph_migrate(temp_path, &[]);
+    assert!(
+        output.status.success(),
+        "migrate failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    assert!(!temp_path.join(".agent").exists());
+    assert!(temp_path.join(".ralph/agent/scratchpad.md").exists());
+    assert!(temp_path.join(".ralph/agent/tasks.jsonl").exists());
+    assert!(temp_path.join(".ralph/events.jsonl").exists());
+}
@@ -148,3 +148,69 @@ fn test_task_show_json() {
     assert_eq!(task.id, task_id);
     assert_eq!(task.title, "Show me");
 }
+
+#[test]
+fn test_task_delete_removes_task() {
+    let temp_dir = TempDir::new().expect("temp dir");
+    let temp_path = temp_dir.path();
+
+    ralph_task_ok(temp_path, &["add", "Delete me"]);
+    let tasks = list_tasks(temp_path, &["--all"]);
+    let task_id = tasks[0].id.clone();
+
+    ralph_task_ok(temp_path, &["delete", &task_id]);
+    assert!(list_tasks(temp_path, &["--all"]).is_empty());
+
+    let output = ralph_task(temp_path, &["delete", &task_id]);
+    assert!(!output.status.success());
+}
+
+#[test]
+fn test_task_bulk_applies_operations_from_file() {
+    let temp_dir = TempDir::new().expect("temp dir");
+    let temp_path = temp_dir.path();
+
+    ralph_task_ok(temp_path, &["add", "Close me"]);
+    ralph_task_ok(temp_path, &["add", "Bump me", "-p", "4"]);
+    let tasks = list_tasks(temp_path, &["--all"]);
+    let close_id = tasks
+        .iter()
+        .find(|t| t.title == "Close me")
+        .unwrap()
+        .id
+        .clone();
+    let bump_id = tasks
+        .iter()
+        .find(|t| t.title == "Bump me")
+        .unwrap()
+        .id
+        .clone();
+
+    let ops = serde_json::json!([
+        {"op": "close", "id": close_id},
+        {"op": "reprioritize", "id": bump_id, "priority": 1},
+        {"op": "delete", "id": "task-missing"},
+    ]);
+    let ops_path = temp_path.join("ops.json");
+    std::fs::write(&ops_path, ops.to_string()).expect("write ops");
+
+    let stdout = ralph_task_ok(
+        temp_path,
+        &[
+            "bulk",
+            "--file",
+            ops_path.to_str().unwrap(),
+            "--format",
+            "json",
+        ],
+    );
+    let results: Vec<serde_json::Value> = serde_json::from_str(&stdout).expect("parse results");
+    let oks: Vec<bool> = results.iter().map(|r| r["ok"].as_bool().unwrap()).collect();
+    assert_eq!(oks, vec![true, true, false]);
+
+    let tasks = list_tasks(temp_path, &["--all"]);
+    let closed = tasks.iter().find(|t| t.id == close_id).unwrap();
+    assert_eq!(closed.status, TaskStatus::Closed);
+    let bumped = tasks.iter().find(|t| t.id == bump_id).unwrap();
+    assert_eq!(bumped.priority, 1);
+}
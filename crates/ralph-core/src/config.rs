@@ -670,6 +670,13 @@ pub struct CoreConfig {
     /// This is especially important for E2E tests that run in isolated workspaces.
     #[serde(skip)]
     pub workspace_root: std::path::PathBuf,
+
+    /// Name of the builtin preset the config was loaded from, if any.
+    ///
+    /// Set by the CLI for `-c builtin:<name>`; recorded on questions so
+    /// interaction stats can be broken down per preset.
+    #[serde(skip)]
+    pub preset: Option<String>,
 }
 
 fn default_scratchpad() -> String {
@@ -701,6 +708,7 @@ impl Default for CoreConfig {
                 .unwrap_or_else(|_| {
                     std::env::current_dir().unwrap_or_else(|_| std::path::PathBuf::from("."))
                 }),
+            preset: None,
         }
     }
 }
@@ -1015,12 +1023,27 @@ pub struct PreflightConfig {
     pub skip: Vec<String>,
 }
 
+/// Retention policy for event log archives in `.ralph/archives/`.
+///
+/// Both limits are optional; when neither is set, archives are kept forever.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ArchiveConfig {
+    /// Delete archives older than this many days.
+    #[serde(default)]
+    pub max_age_days: Option<u32>,
+
+    /// Keep at most this many archives (newest first).
+    #[serde(default)]
+    pub max_count: Option<usize>,
+}
+
 /// Feature flags for optional Ralph capabilities.
 ///
 /// Example configuration:
 /// ```yaml
 /// features:
 ///   parallel: true  # Enable parallel loops via git worktrees
+///   max_parallel_loops: 3  # Optional cap on concurrent worktree loops
 ///   auto_merge: false  # Auto-merge worktree branches on completion
 ///   preflight:
 ///     enabled: false      # Opt-in: run preflight checks before `ralph run`
@@ -1029,6 +1052,9 @@ pub struct PreflightConfig {
 ///   loop_naming:
 ///     format: human-readable  # or "timestamp" for legacy format
 ///     max_length: 50
+///   archive:
+///     max_age_days: 30  # Prune event log archives older than this
+///     max_count: 20     # Keep at most this many archives
 /// ```
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FeaturesConfig {
@@ -1039,6 +1065,14 @@ pub struct FeaturesConfig {
     #[serde(default = "default_true")]
     pub parallel: bool,
 
+    /// Maximum number of worktree loops that may run at once.
+    ///
+    /// When the cap is reached, `ralph run` errors instead of spawning
+    /// another worktree loop. The primary loop doesn't count toward it.
+    /// Unset (default) means no limit.
+    #[serde(default)]
+    pub max_parallel_loops: Option<usize>,
+
     /// Whether to automatically merge worktree branches on completion.
     ///
     /// When false (default), completed worktree loops queue for manual merge.
@@ -1058,15 +1092,21 @@ pub struct FeaturesConfig {
     /// Preflight check configuration.
     #[serde(default)]
     pub preflight: PreflightConfig,
+
+    /// Retention policy for archived event logs.
+    #[serde(default)]
+    pub archive: ArchiveConfig,
 }
 
 impl Default for FeaturesConfig {
     fn default() -> Self {
         Self {
-            parallel: true,    // Parallel loops enabled by default
+            parallel: true, // Parallel loops enabled by default
+            max_parallel_loops: None,
             auto_merge: false, // Auto-merge disabled by default for safety
             loop_naming: crate::loop_name::LoopNamingConfig::default(),
             preflight: PreflightConfig::default(),
+            archive: ArchiveConfig::default(),
         }
     }
 }
@@ -2282,6 +2322,19 @@ features:
         assert!(config.features.auto_merge, "auto_merge should be true");
     }
 
+    #[test]
+    fn test_features_config_max_parallel_loops() {
+        assert_eq!(RalphConfig::default().features.max_parallel_loops, None);
+
+        let yaml = r"
+features:
+  max_parallel_loops: 2
+";
+        let config: RalphConfig = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(config.features.max_parallel_loops, Some(2));
+        assert!(config.features.parallel, "parallel should stay enabled");
+    }
+
     #[test]
     fn test_skills_config_defaults_when_absent() {
         // Configs without a skills: section should still parse (backwards compat)
@@ -0,0 +1,394 @@
+//! Archiving and retention for per-run event logs.
+//!
+//! Every `ralph run` writes `.ralph/events-{run_id}.jsonl`, so event logs pile
+//! up indefinitely. Inactive logs (anything other than the file named by
+//! `.ralph/current-events`) can be compressed into `.ralph/archives/`, and old
+//! archives are pruned according to [`ArchiveConfig`]. The scratchpad,
+//! iteration summary and handoff notes belong to the most recent run, so they
+//! are only snapshotted into the archive of the current log.
+//!
+//! Compression shells out to `tar`, the same way git operations shell out to
+//! `git`.
+
+use crate::config::ArchiveConfig;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Directory under `.ralph/` holding archives.
+pub const ARCHIVES_DIR: &str = "archives";
+
+/// Agent files snapshotted alongside the event log, relative to `.ralph/`.
+const SNAPSHOT_FILES: &[&str] = &[
+    "agent/scratchpad.md",
+    "agent/summary.md",
+    "agent/handoff-notes.md",
+];
+
+/// An archived event log.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ArchiveInfo {
+    /// Path to the `.tar.gz` file.
+    pub path: PathBuf,
+    /// Archive size in bytes.
+    pub size_bytes: u64,
+    /// When the archive was written.
+    pub created_at: DateTime<Utc>,
+}
+
+/// Returns the file name of the log `.ralph/current-events` points at.
+fn current_log_name(ralph_dir: &Path) -> Option<std::ffi::OsString> {
+    fs::read_to_string(ralph_dir.join("current-events"))
+        .ok()
+        .and_then(|s| Path::new(s.trim()).file_name().map(ToOwned::to_owned))
+}
+
+/// Returns the current run's event log, if the marker names one that exists.
+pub fn current_event_log(ralph_dir: &Path) -> Option<PathBuf> {
+    current_log_name(ralph_dir)
+        .map(|name| ralph_dir.join(name))
+        .filter(|path| path.is_file())
+}
+
+/// Returns event logs in `ralph_dir` that no run is currently writing to.
+///
+/// The active log is whichever file `.ralph/current-events` points at.
+pub fn inactive_event_logs(ralph_dir: &Path) -> io::Result<Vec<PathBuf>> {
+    if !ralph_dir.is_dir() {
+        return Ok(Vec::new());
+    }
+
+    let current = current_log_name(ralph_dir);
+
+    let mut logs = Vec::new();
+    for entry in fs::read_dir(ralph_dir)? {
+        let path = entry?.path();
+        let is_event_log = path.is_file()
+            && path.extension().is_some_and(|ext| ext == "jsonl")
+            && path
+                .file_stem()
+                .and_then(|s| s.to_str())
+                .is_some_and(|s| s.starts_with("events"));
+        if is_event_log && path.file_name() != current.as_deref() {
+            logs.push(path);
+        }
+    }
+    logs.sort();
+    Ok(logs)
+}
+
+/// Compresses an event log into `.ralph/archives/{stem}.tar.gz` and removes it.
+///
+/// If the log is the current run's (named by `.ralph/current-events`), the
+/// scratchpad, summary and handoff notes are included as they stand, and the
+/// marker is removed along with the log. Historical logs are archived alone,
+/// since those files describe a later run. Existing archives are never
+/// overwritten: if the name is taken (e.g. a second `events.jsonl`), a
+/// numeric suffix is added. Returns the archive path.
+pub fn archive_event_log(ralph_dir: &Path, events_path: &Path) -> io::Result<PathBuf> {
+    let (Some(file_name), Some(stem)) = (events_path.file_name(), events_path.file_stem()) else {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("not an event log: {}", events_path.display()),
+        ));
+    };
+
+    let archives_dir = ralph_dir.join(ARCHIVES_DIR);
+    fs::create_dir_all(&archives_dir)?;
+    let archive_path = unique_archive_path(&archives_dir, &stem.to_string_lossy());
+    let is_current = current_log_name(ralph_dir).as_deref() == Some(file_name);
+
+    let mut cmd = Command::new("tar");
+    cmd.arg("-czf")
+        .arg(&archive_path)
+        .arg("-C")
+        .arg(ralph_dir)
+        .arg(file_name);
+    if is_current {
+        for snapshot in SNAPSHOT_FILES {
+            if ralph_dir.join(snapshot).is_file() {
+                cmd.arg(snapshot);
+            }
+        }
+    }
+
+    let output = cmd.output()?;
+    if !output.status.success() {
+        let _ = fs::remove_file(&archive_path);
+        return Err(io::Error::other(format!(
+            "tar failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+
+    fs::remove_file(events_path)?;
+    if is_current {
+        fs::remove_file(ralph_dir.join("current-events"))?;
+    }
+    Ok(archive_path)
+}
+
+/// Returns `{stem}.tar.gz` in `archives_dir`, or `{stem}-{n}.tar.gz` for the
+/// first `n` that doesn't exist yet.
+fn unique_archive_path(archives_dir: &Path, stem: &str) -> PathBuf {
+    let mut path = archives_dir.join(format!("{stem}.tar.gz"));
+    let mut n = 0;
+    while path.exists() {
+        n += 1;
+        path = archives_dir.join(format!("{stem}-{n}.tar.gz"));
+    }
+    path
+}
+
+/// Lists archives in `.ralph/archives/`, newest first.
+pub fn list_archives(ralph_dir: &Path) -> io::Result<Vec<ArchiveInfo>> {
+    let archives_dir = ralph_dir.join(ARCHIVES_DIR);
+    if !archives_dir.is_dir() {
+        return Ok(Vec::new());
+    }
+
+    let mut archives = Vec::new();
+    for entry in fs::read_dir(&archives_dir)? {
+        let entry = entry?;
+        let path = entry.path();
+        if !path.to_string_lossy().ends_with(".tar.gz") {
+            continue;
+        }
+        let metadata = entry.metadata()?;
+        archives.push(ArchiveInfo {
+            path,
+            size_bytes: metadata.len(),
+            created_at: metadata.modified()?.into(),
+        });
+    }
+    archives.sort_by_key(|a| std::cmp::Reverse(a.created_at));
+    Ok(archives)
+}
+
+/// Returns the archives that fall outside the retention policy, without
+/// deleting anything.
+///
+/// `pending` is the number of archives about to be written; they are newer
+/// than every existing archive, so they take up `max_count` slots first.
+pub fn archives_outside_retention(
+    ralph_dir: &Path,
+    policy: &ArchiveConfig,
+    now: DateTime<Utc>,
+    pending: usize,
+) -> io::Result<Vec<ArchiveInfo>> {
+    let cutoff = policy
+        .max_age_days
+        .map(|days| now - chrono::Duration::days(i64::from(days)));
+    let max_count = policy.max_count.unwrap_or(usize::MAX);
+
+    let mut outside = Vec::new();
+    let mut kept = pending;
+    for archive in list_archives(ralph_dir)? {
+        let expired = cutoff.is_some_and(|cutoff| archive.created_at < cutoff);
+        if expired || kept >= max_count {
+            outside.push(archive);
+        } else {
+            kept += 1;
+        }
+    }
+    Ok(outside)
+}
+
+/// Deletes archives that fall outside the retention policy.
+///
+/// Archives older than `max_age_days` are removed, then the oldest remaining
+/// archives beyond `max_count`. Returns the deleted paths.
+pub fn enforce_retention(
+    ralph_dir: &Path,
+    policy: &ArchiveConfig,
+    now: DateTime<Utc>,
+) -> io::Result<Vec<PathBuf>> {
+    let mut removed = Vec::new();
+    for archive in archives_outside_retention(ralph_dir, policy, now, 0)? {
+        fs::remove_file(&archive.path)?;
+        removed.push(archive.path);
+    }
+    Ok(removed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn write(path: &Path, content: &str) {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    }
+
+    #[test]
+    fn test_inactive_event_logs_skips_current() {
+        let tmp = TempDir::new().unwrap();
+        let ralph_dir = tmp.path();
+        write(&ralph_dir.join("events-20250101-000000.jsonl"), "{}");
+        write(&ralph_dir.join("events-20250102-000000.jsonl"), "{}");
+        write(&ralph_dir.join("history.jsonl"), "{}");
+        write(
+            &ralph_dir.join("current-events"),
+            ".ralph/events-20250102-000000.jsonl",
+        );
+
+        let logs = inactive_event_logs(ralph_dir).unwrap();
+        assert_eq!(logs, vec![ralph_dir.join("events-20250101-000000.jsonl")]);
+    }
+
+    fn archive_listing(archive: &Path) -> String {
+        let listing = Command::new("tar")
+            .arg("-tzf")
+            .arg(archive)
+            .output()
+            .unwrap();
+        String::from_utf8_lossy(&listing.stdout).into_owned()
+    }
+
+    #[test]
+    fn test_archive_event_log_compresses_and_removes() {
+        let tmp = TempDir::new().unwrap();
+        let ralph_dir = tmp.path();
+        let events = ralph_dir.join("events-20250101-000000.jsonl");
+        write(&events, r#"{"topic":"build.done"}"#);
+        write(&ralph_dir.join("agent/scratchpad.md"), "# Notes");
+        write(
+            &ralph_dir.join("current-events"),
+            ".ralph/events-20250101-000000.jsonl",
+        );
+
+        let archive = archive_event_log(ralph_dir, &events).unwrap();
+
+        assert_eq!(
+            archive,
+            ralph_dir.join("archives/events-20250101-000000.tar.gz")
+        );
+        assert!(!events.exists());
+        assert!(!ralph_dir.join("current-events").exists());
+
+        let listing = archive_listing(&archive);
+        assert!(listing.contains("events-20250101-000000.jsonl"));
+        assert!(listing.contains("agent/scratchpad.md"));
+        assert!(!listing.contains("agent/summary.md"));
+    }
+
+    #[test]
+    fn test_historical_archives_exclude_live_agent_files() {
+        let tmp = TempDir::new().unwrap();
+        let ralph_dir = tmp.path();
+        write(&ralph_dir.join("agent/scratchpad.md"), "# Current run");
+        write(&ralph_dir.join("agent/summary.md"), "# Current summary");
+        write(&ralph_dir.join("events-20250103-000000.jsonl"), "{}");
+        write(
+            &ralph_dir.join("current-events"),
+            ".ralph/events-20250103-000000.jsonl",
+        );
+        for name in [
+            "events-20250101-000000.jsonl",
+            "events-20250102-000000.jsonl",
+        ] {
+            write(&ralph_dir.join(name), "{}");
+        }
+
+        for log in inactive_event_logs(ralph_dir).unwrap() {
+            let archive = archive_event_log(ralph_dir, &log).unwrap();
+            let listing = archive_listing(&archive);
+            assert!(listing.contains(&*log.file_name().unwrap().to_string_lossy()));
+            assert!(
+                !listing.contains("agent/"),
+                "unexpected snapshot: {listing}"
+            );
+        }
+
+        assert_eq!(list_archives(ralph_dir).unwrap().len(), 2);
+        // The current run's log and marker are untouched
+        assert_eq!(
+            current_event_log(ralph_dir),
+            Some(ralph_dir.join("events-20250103-000000.jsonl"))
+        );
+    }
+
+    #[test]
+    fn test_archive_event_log_never_overwrites() {
+        let tmp = TempDir::new().unwrap();
+        let ralph_dir = tmp.path();
+        let events = ralph_dir.join("events.jsonl");
+
+        write(&events, r#"{"topic":"first"}"#);
+        let first = archive_event_log(ralph_dir, &events).unwrap();
+        write(&events, r#"{"topic":"second"}"#);
+        let second = archive_event_log(ralph_dir, &events).unwrap();
+
+        assert_eq!(first, ralph_dir.join("archives/events.tar.gz"));
+        assert_eq!(second, ralph_dir.join("archives/events-1.tar.gz"));
+        assert!(first.exists());
+        assert_eq!(list_archives(ralph_dir).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_archives_outside_retention_reserves_pending_slots() {
+        let tmp = TempDir::new().unwrap();
+        let ralph_dir = tmp.path();
+        for name in ["a", "b"] {
+            write(&ralph_dir.join(format!("archives/{name}.tar.gz")), name);
+        }
+        let keep_two = ArchiveConfig {
+            max_age_days: None,
+            max_count: Some(2),
+        };
+
+        let now = Utc::now();
+        assert!(
+            archives_outside_retention(ralph_dir, &keep_two, now, 0)
+                .unwrap()
+                .is_empty()
+        );
+        assert_eq!(
+            archives_outside_retention(ralph_dir, &keep_two, now, 1)
+                .unwrap()
+                .len(),
+            1
+        );
+        // Nothing is deleted
+        assert_eq!(list_archives(ralph_dir).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_enforce_retention_by_count_and_age() {
+        let tmp = TempDir::new().unwrap();
+        let ralph_dir = tmp.path();
+        for name in ["a", "b", "c"] {
+            write(&ralph_dir.join(format!("archives/{name}.tar.gz")), name);
+        }
+
+        let keep_two = ArchiveConfig {
+            max_age_days: None,
+            max_count: Some(2),
+        };
+        let removed = enforce_retention(ralph_dir, &keep_two, Utc::now()).unwrap();
+        assert_eq!(removed.len(), 1);
+        assert_eq!(list_archives(ralph_dir).unwrap().len(), 2);
+
+        let max_age = ArchiveConfig {
+            max_age_days: Some(7),
+            max_count: None,
+        };
+        let future = Utc::now() + chrono::Duration::days(8);
+        let removed = enforce_retention(ralph_dir, &max_age, future).unwrap();
+        assert_eq!(removed.len(), 2);
+        assert!(list_archives(ralph_dir).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_enforce_retention_without_limits_keeps_everything() {
+        let tmp = TempDir::new().unwrap();
+        write(&tmp.path().join("archives/a.tar.gz"), "a");
+
+        let removed = enforce_retention(tmp.path(), &ArchiveConfig::default(), Utc::now()).unwrap();
+        assert!(removed.is_empty());
+    }
+}
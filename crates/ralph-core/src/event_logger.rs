@@ -3,6 +3,7 @@
 //! Logs all events to `.ralph/events.jsonl` as specified in the event-loop spec.
 //! The observer pattern allows hooking into the event bus without modifying routing.
 
+use crate::event_reader::prefer_field;
 use crate::loop_context::LoopContext;
 use ralph_proto::{Event, HatId};
 use serde::{Deserialize, Deserializer, Serialize};
@@ -44,24 +45,25 @@ where
 
 /// A logged event record for debugging.
 ///
-/// Supports two schemas:
+/// Supports three schemas:
 /// 1. Rich internal format (logged by Ralph):
 ///    `{"ts":"2024-01-15T10:23:45Z","iteration":1,"hat":"loop","topic":"task.start","triggered":"planner","payload":"..."}`
 /// 2. Simple agent format (written by agents):
 ///    `{"topic":"build.task","payload":"...","ts":"2024-01-15T10:24:12Z"}`
+/// 3. Legacy format (`type`/`timestamp` instead of `topic`/`ts`):
+///    `{"type":"build.task","payload":"...","timestamp":"2024-01-15T10:24:12Z"}`
 ///
 /// Fields that don't exist in the agent format default to sensible values.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(try_from = "RawEventRecord")]
 pub struct EventRecord {
     /// ISO 8601 timestamp.
     pub ts: String,
 
     /// Loop iteration number (0 if not provided by agent-written events).
-    #[serde(default)]
     pub iteration: u32,
 
     /// Hat that was active when event was published (empty string if not provided).
-    #[serde(default)]
     pub hat: String,
 
     /// Event topic.
@@ -73,7 +75,6 @@ pub struct EventRecord {
 
     /// Event content (truncated if large). Defaults to empty string for agent events without payload.
     /// Accepts both string and object payloads - objects are serialized to JSON strings.
-    #[serde(default, deserialize_with = "deserialize_flexible_payload")]
     pub payload: String,
 
     /// How many times this task has blocked (optional).
@@ -81,6 +82,43 @@ pub struct EventRecord {
     pub blocked_count: Option<u32>,
 }
 
+/// On-disk shape of [`EventRecord`], including the legacy field names.
+///
+/// `topic` and `ts` win over `type` and `timestamp` when a line has both.
+#[derive(Deserialize)]
+struct RawEventRecord {
+    ts: Option<String>,
+    #[serde(rename = "timestamp")]
+    legacy_ts: Option<serde_json::Value>,
+    #[serde(default)]
+    iteration: u32,
+    #[serde(default)]
+    hat: String,
+    topic: Option<String>,
+    #[serde(rename = "type")]
+    legacy_topic: Option<serde_json::Value>,
+    triggered: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_flexible_payload")]
+    payload: String,
+    blocked_count: Option<u32>,
+}
+
+impl TryFrom<RawEventRecord> for EventRecord {
+    type Error = String;
+
+    fn try_from(raw: RawEventRecord) -> Result<Self, Self::Error> {
+        Ok(Self {
+            ts: prefer_field(raw.ts, raw.legacy_ts.as_ref(), "ts")?,
+            iteration: raw.iteration,
+            hat: raw.hat,
+            topic: prefer_field(raw.topic, raw.legacy_topic.as_ref(), "topic")?,
+            triggered: raw.triggered,
+            payload: raw.payload,
+            blocked_count: raw.blocked_count,
+        })
+    }
+}
+
 impl EventRecord {
     /// Maximum payload length before truncation.
     const MAX_PAYLOAD_LEN: usize = 500;
@@ -513,6 +551,52 @@ mod tests {
         assert_eq!(records[1].hat, ""); // Defaulted
     }
 
+    #[test]
+    fn test_legacy_type_timestamp_format() {
+        let tmp = TempDir::new().unwrap();
+        let path = tmp.path().join("events.jsonl");
+
+        let mut file = File::create(&path).unwrap();
+        writeln!(
+            file,
+            r#"{{"type":"iteration.started","payload":"2","timestamp":"2024-01-15T10:00:00Z"}}"#
+        )
+        .unwrap();
+
+        let history = EventHistory::new(&path);
+        let records = history.read_all().unwrap();
+
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].topic, "iteration.started");
+        assert_eq!(records[0].ts, "2024-01-15T10:00:00Z");
+
+        // Re-serialized records use the canonical field names
+        let json = serde_json::to_string(&records[0]).unwrap();
+        assert!(json.contains(r#""topic":"iteration.started""#));
+        assert!(json.contains(r#""ts":"2024-01-15T10:00:00Z""#));
+    }
+
+    #[test]
+    fn test_canonical_fields_win_over_legacy_fields() {
+        let tmp = TempDir::new().unwrap();
+        let path = tmp.path().join("events.jsonl");
+
+        let mut file = File::create(&path).unwrap();
+        writeln!(
+            file,
+            r#"{{"topic":"build.done","type":"legacy.topic","ts":"2024-01-15T10:00:00Z","timestamp":"1999-01-01T00:00:00Z","iteration":3}}"#
+        )
+        .unwrap();
+
+        let history = EventHistory::new(&path);
+        let records = history.read_all().unwrap();
+
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].topic, "build.done");
+        assert_eq!(records[0].ts, "2024-01-15T10:00:00Z");
+        assert_eq!(records[0].iteration, 3);
+    }
+
     #[test]
     fn test_object_payload_from_ralph_emit_json() {
         // Test that `ralph emit --json` object payloads are parsed correctly
@@ -16,7 +16,11 @@ use crate::hatless_ralph::HatlessRalph;
 use crate::instructions::InstructionBuilder;
 use crate::loop_context::LoopContext;
 use crate::memory_store::{MarkdownMemoryStore, format_memories_as_markdown, truncate_to_budget};
+use crate::pinned_files::{DEFAULT_PINNED_CHAR_BUDGET, read_pinned_manifest, render_pinned_files};
+use crate::question_store::{Question, QuestionStore};
 use crate::skill_registry::SkillRegistry;
+use crate::steering::SteeringCommand;
+use crate::task_store::{TaskOperation, TaskStore};
 use crate::text::floor_char_boundary;
 use ralph_proto::{CheckinContext, Event, EventBus, Hat, HatId, RobotService};
 use std::path::PathBuf;
@@ -25,6 +29,9 @@ use std::sync::atomic::AtomicBool;
 use std::time::Duration;
 use tracing::{debug, info, warn};
 
+/// Maximum number of past answers offered alongside a `human.interact` question.
+const MAX_SUGGESTED_RESPONSES: usize = 3;
+
 /// Reason the event loop terminated.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum TerminationReason {
@@ -108,7 +115,14 @@ pub struct EventLoop {
     instruction_builder: InstructionBuilder,
     ralph: HatlessRalph,
     /// Cached human guidance messages that should persist across iterations.
+    ///
+    /// Everything cached here has already been injected into a prompt.
     robot_guidance: Vec<String>,
+    /// Guidance-topic events (guidance and retracts) taken from the bus.
+    consumed_guidance: usize,
+    /// IDs of `human.interact` questions asked by this loop, so only its own
+    /// overdue questions produce `human.timeout` events.
+    asked_questions: Vec<String>,
     /// Event reader for consuming events from JSONL file.
     /// Made pub(crate) to allow tests to override the path.
     pub(crate) event_reader: EventReader,
@@ -243,6 +257,8 @@ impl EventLoop {
             instruction_builder,
             ralph,
             robot_guidance: Vec::new(),
+            consumed_guidance: 0,
+            asked_questions: Vec::new(),
             event_reader,
             diagnostics,
             loop_context: Some(context),
@@ -333,6 +349,8 @@ impl EventLoop {
             instruction_builder,
             ralph,
             robot_guidance: Vec::new(),
+            consumed_guidance: 0,
+            asked_questions: Vec::new(),
             event_reader,
             diagnostics,
             loop_context: None,
@@ -365,6 +383,100 @@ impl EventLoop {
             .unwrap_or_else(|| PathBuf::from(".ralph/agent/tasks.jsonl"))
     }
 
+    /// Returns the pinned-files manifest path based on loop context or default.
+    fn pinned_files_path(&self) -> PathBuf {
+        self.loop_context
+            .as_ref()
+            .map(|ctx| ctx.pinned_files_path())
+            .unwrap_or_else(|| PathBuf::from(".ralph/agent/pinned-files"))
+    }
+
+    /// Returns the questions path based on loop context or default.
+    fn questions_path(&self) -> PathBuf {
+        self.loop_context
+            .as_ref()
+            .map(|ctx| ctx.questions_path())
+            .unwrap_or_else(|| PathBuf::from(".ralph/agent/questions.jsonl"))
+    }
+
+    /// Records a newly asked question, tagged with the asking loop, hat and preset.
+    ///
+    /// Returns the question ID, or `None` if the store couldn't be written.
+    fn record_question(&self, text: &str, timeout_secs: u64) -> Option<String> {
+        let loop_id = self
+            .loop_context
+            .as_ref()
+            .and_then(|ctx| ctx.loop_id())
+            .map(str::to_string);
+        let question = Question::new(text, chrono::Utc::now(), timeout_secs)
+            .with_loop_id(loop_id)
+            .with_hat(self.state.last_hat.as_ref().map(ToString::to_string))
+            .with_preset(self.config.core.preset.clone());
+        let result = QuestionStore::load(&self.questions_path())
+            .and_then(|mut store| store.with_exclusive_lock(|s| s.add(question).id.clone()));
+        result
+            .map_err(|e| warn!(error = %e, "Failed to record human.interact question"))
+            .ok()
+    }
+
+    /// Expires overdue questions in the store.
+    ///
+    /// Returns a `human.timeout` event, carrying the question text, for each
+    /// expired question this loop asked itself (e.g. one whose wait failed).
+    /// Stale questions from other runs are only marked expired, since the
+    /// current agent never asked them.
+    fn expire_overdue_questions(&self) -> Vec<Event> {
+        let now = chrono::Utc::now();
+        let result = QuestionStore::load(&self.questions_path())
+            .and_then(|mut store| store.with_exclusive_lock(|s| s.expire_overdue(now)));
+        match result {
+            Ok(expired) => expired
+                .into_iter()
+                .filter(|question| self.asked_questions.contains(&question.id))
+                .map(|question| Event::new("human.timeout", &question.text))
+                .collect(),
+            Err(e) => {
+                warn!(error = %e, "Failed to expire overdue human.interact questions");
+                Vec::new()
+            }
+        }
+    }
+
+    /// Appends answers to similar past questions so the human can reuse one.
+    fn question_with_suggestions(&self, question: &str) -> String {
+        let suggestions = QuestionStore::load(&self.questions_path())
+            .map(|store| store.suggest_responses(question, MAX_SUGGESTED_RESPONSES))
+            .unwrap_or_default();
+        if suggestions.is_empty() {
+            return question.to_string();
+        }
+
+        let mut message = format!("{question}\n\nPrevious answers to similar questions:");
+        for suggestion in suggestions {
+            message.push_str("\n- ");
+            message.push_str(&suggestion);
+        }
+        message
+    }
+
+    /// Marks a recorded question as answered (with `response`) or expired (without).
+    fn resolve_question(&self, id: &str, response: Option<&str>) {
+        let now = chrono::Utc::now();
+        let result = QuestionStore::load(&self.questions_path()).and_then(|mut store| {
+            store.with_exclusive_lock(|s| match response {
+                Some(response) => {
+                    s.answer(id, response, now);
+                }
+                None => {
+                    s.expire(id, now);
+                }
+            })
+        });
+        if let Err(e) = result {
+            warn!(error = %e, question_id = %id, "Failed to update question store");
+        }
+    }
+
     /// Returns the scratchpad path based on loop context or config.
     fn scratchpad_path(&self) -> PathBuf {
         self.loop_context
@@ -681,7 +793,7 @@ impl EventLoop {
                 // Separate human.guidance events from regular events
                 let (guidance_events, regular_events): (Vec<_>, Vec<_>) = events
                     .into_iter()
-                    .partition(|e| e.topic.as_str() == "human.guidance");
+                    .partition(|e| Self::is_guidance_topic(e.topic.as_str()));
 
                 let events_context = regular_events
                     .iter()
@@ -697,7 +809,8 @@ impl EventLoop {
                 let base_prompt = self.ralph.build_prompt(&events_context, &[]);
                 self.ralph.clear_robot_guidance();
                 let with_skills = self.prepend_auto_inject_skills(base_prompt);
-                let with_scratchpad = self.prepend_scratchpad(with_skills);
+                let with_pinned = self.prepend_pinned_files(with_skills);
+                let with_scratchpad = self.prepend_scratchpad(with_pinned);
                 let final_prompt = self.prepend_ready_tasks(with_scratchpad);
 
                 debug!("build_prompt: routing to HatlessRalph (solo mode)");
@@ -742,7 +855,7 @@ impl EventLoop {
                 // Separate human.guidance events from regular events
                 let (guidance_events, regular_events): (Vec<_>, Vec<_>) = all_events
                     .into_iter()
-                    .partition(|e| e.topic.as_str() == "human.guidance");
+                    .partition(|e| Self::is_guidance_topic(e.topic.as_str()));
 
                 // Persist and inject human guidance before building prompt (must happen before
                 // immutable borrows from determine_active_hats)
@@ -776,7 +889,8 @@ impl EventLoop {
                 // Clear guidance after active_hats references are no longer needed
                 self.ralph.clear_robot_guidance();
                 let with_skills = self.prepend_auto_inject_skills(base_prompt);
-                let with_scratchpad = self.prepend_scratchpad(with_skills);
+                let with_pinned = self.prepend_pinned_files(with_skills);
+                let with_scratchpad = self.prepend_scratchpad(with_pinned);
                 let final_prompt = self.prepend_ready_tasks(with_scratchpad);
 
                 return Some(final_prompt);
@@ -813,30 +927,135 @@ impl EventLoop {
         )
     }
 
+    /// Returns true for topics handled by [`Self::update_robot_guidance`].
+    fn is_guidance_topic(topic: &str) -> bool {
+        topic == "human.guidance" || topic == "human.guidance.retract"
+    }
+
     /// Stores guidance payloads, persists them to scratchpad, and prepares them for prompt injection.
     ///
     /// Guidance events are ephemeral in the event bus (consumed by `take_pending`).
     /// This method both caches them in memory for prompt injection and appends
     /// them to the scratchpad file so they survive across process restarts.
+    ///
+    /// Payloads that parse as a [`SteeringCommand`] are rendered as directives;
+    /// `change_priority` is also applied to the task store; if that fails, the
+    /// agent is told the priority is unchanged instead.
+    ///
+    /// A `human.guidance.retract` event withdraws the most recent guidance
+    /// only if it arrived in the same batch, before it reached the agent.
+    /// Guidance that has already been injected into a prompt can't be taken
+    /// back, so retracts of it are ignored.
     fn update_robot_guidance(&mut self, guidance_events: Vec<Event>) {
         if guidance_events.is_empty() {
             return;
         }
+        self.consumed_guidance += guidance_events.len();
+        self.record_consumed_guidance();
+
+        let mut fresh: Vec<(String, Option<SteeringCommand>)> = Vec::new();
+        for event in guidance_events {
+            if event.topic.as_str() == "human.guidance.retract" {
+                if fresh.pop().is_none() {
+                    warn!("Ignoring guidance retract: the guidance was already delivered");
+                }
+                continue;
+            }
+            match SteeringCommand::parse(&event.payload) {
+                Some(command) => fresh.push((command.to_guidance(), Some(command))),
+                None => fresh.push((event.payload, None)),
+            }
+        }
+
+        let guidance: Vec<String> = fresh
+            .into_iter()
+            .map(|(message, command)| match command {
+                Some(SteeringCommand::ChangePriority { task_id, priority }) => {
+                    match self.reprioritize_task(&task_id, priority) {
+                        Ok(()) => message,
+                        // Don't tell the agent about a change that didn't happen
+                        Err(reason) => format!(
+                            "Could not reprioritize {task_id}: {reason}. Its priority is unchanged."
+                        ),
+                    }
+                }
+                _ => message,
+            })
+            .collect();
 
         // Persist new guidance to scratchpad before caching
-        self.persist_guidance_to_scratchpad(&guidance_events);
+        self.persist_guidance_to_scratchpad(&guidance);
 
-        self.robot_guidance
-            .extend(guidance_events.into_iter().map(|e| e.payload));
+        self.robot_guidance.extend(guidance);
+    }
+
+    /// Writes the consumed-guidance marker for the current events file.
+    ///
+    /// The marker is `{"events": <current-events contents>, "consumed": <n>}`;
+    /// readers should ignore it when `events` doesn't match their own
+    /// `current-events`, since it then describes an earlier run.
+    fn record_consumed_guidance(&self) {
+        let Some(ctx) = self.loop_context.as_ref() else {
+            return;
+        };
+        let events = std::fs::read_to_string(ctx.current_events_marker()).unwrap_or_default();
+        let marker = serde_json::json!({
+            "events": events.trim(),
+            "consumed": self.consumed_guidance,
+        });
+        if let Err(e) = std::fs::write(ctx.guidance_marker_path(), marker.to_string()) {
+            warn!(error = %e, "Failed to record consumed guidance");
+        }
+    }
+
+    /// Applies a `change_priority` steering command to the task store.
+    ///
+    /// Returns the reason the change wasn't applied, if it wasn't.
+    fn reprioritize_task(&self, task_id: &str, priority: u8) -> Result<(), String> {
+        let op = TaskOperation::Reprioritize {
+            id: task_id.to_string(),
+            priority,
+        };
+        let tasks_path = self.tasks_path();
+        let resolved_path = if tasks_path.is_relative() {
+            self.config.core.workspace_root.join(&tasks_path)
+        } else {
+            tasks_path
+        };
+        match TaskStore::load(&resolved_path).and_then(|mut store| store.apply_bulk(&[op])) {
+            Ok(results) => match results.into_iter().find(|r| !r.ok) {
+                None => {
+                    info!(task_id = %task_id, priority, "Applied change_priority steering command");
+                    Ok(())
+                }
+                Some(result) => {
+                    let reason = result.error.unwrap_or_else(|| "rejected".to_string());
+                    warn!(task_id = %task_id, reason = %reason, "change_priority steering not applied");
+                    Err(reason)
+                }
+            },
+            Err(e) if e.kind() == std::io::ErrorKind::InvalidInput => {
+                warn!(error = %e, "Rejected change_priority steering command");
+                Err(e.to_string())
+            }
+            Err(e) => {
+                warn!(error = %e, "Failed to apply change_priority steering command");
+                Err("task store unavailable".to_string())
+            }
+        }
     }
 
     /// Appends human guidance entries to the scratchpad file for durability.
     ///
     /// Each guidance message is written as a timestamped markdown entry so it
     /// appears alongside the agent's own thinking and survives process restarts.
-    fn persist_guidance_to_scratchpad(&self, guidance_events: &[Event]) {
+    fn persist_guidance_to_scratchpad(&self, guidance: &[String]) {
         use std::io::Write;
 
+        if guidance.is_empty() {
+            return;
+        }
+
         let scratchpad_path = self.scratchpad_path();
         let resolved_path = if scratchpad_path.is_relative() {
             self.config.core.workspace_root.join(&scratchpad_path)
@@ -866,18 +1085,15 @@ impl EventLoop {
         };
 
         let timestamp = chrono::Utc::now().format("%Y-%m-%d %H:%M:%S UTC");
-        for event in guidance_events {
-            let entry = format!(
-                "\n### HUMAN GUIDANCE ({})\n\n{}\n",
-                timestamp, event.payload
-            );
+        for message in guidance {
+            let entry = format!("\n### HUMAN GUIDANCE ({})\n\n{}\n", timestamp, message);
             if let Err(e) = file.write_all(entry.as_bytes()) {
                 warn!("Failed to write guidance to scratchpad: {}", e);
             }
         }
 
         info!(
-            count = guidance_events.len(),
+            count = guidance.len(),
             "Persisted human guidance to scratchpad"
         );
     }
@@ -1126,6 +1342,34 @@ impl EventLoop {
         final_prompt
     }
 
+    /// Prepends pinned context files listed in the pinned-files manifest.
+    ///
+    /// The manifest is re-read every iteration so edits take effect on the
+    /// next prompt without restarting the loop.
+    fn prepend_pinned_files(&self, prompt: String) -> String {
+        let manifest_path = self.pinned_files_path();
+        let resolved_path = if manifest_path.is_relative() {
+            self.config.core.workspace_root.join(&manifest_path)
+        } else {
+            manifest_path
+        };
+
+        let paths = read_pinned_manifest(&resolved_path);
+        let Some(section) = render_pinned_files(
+            &self.config.core.workspace_root,
+            &paths,
+            DEFAULT_PINNED_CHAR_BUDGET,
+        ) else {
+            return prompt;
+        };
+
+        info!("Injecting {} pinned file(s) into prompt", paths.len());
+
+        let mut final_prompt = section;
+        final_prompt.push_str(&prompt);
+        final_prompt
+    }
+
     /// Prepends ready tasks to the prompt if tasks are enabled and any exist.
     ///
     /// Loads the task store and formats ready (unblocked, open) tasks into
@@ -1959,6 +2203,8 @@ impl EventLoop {
         // When a human.interact event is detected and robot service is active,
         // send the question and block until human.response or timeout.
         let mut response_event = None;
+        let mut overdue_timeouts = Vec::new();
+        let mut asked_question = None;
         let ask_human_idx = validated_events
             .iter()
             .position(|e| e.topic == "human.interact".into());
@@ -1974,7 +2220,8 @@ impl EventLoop {
                 );
 
                 // Send the question (includes retry with exponential backoff)
-                let send_ok = match robot_service.send_question(&payload) {
+                let message = self.question_with_suggestions(&payload);
+                let send_ok = match robot_service.send_question(&message) {
                     Ok(_message_id) => true,
                     Err(e) => {
                         warn!(
@@ -2020,12 +2267,19 @@ impl EventLoop {
                                 .unwrap_or_else(|| PathBuf::from(".ralph/events.jsonl"))
                         });
 
+                    overdue_timeouts = self.expire_overdue_questions();
+                    let question_id = self.record_question(&payload, robot_service.timeout_secs());
+                    asked_question.clone_from(&question_id);
+
                     match robot_service.wait_for_response(&events_path) {
                         Ok(Some(response)) => {
                             info!(
                                 response = %response,
                                 "Received human.response — continuing loop"
                             );
+                            if let Some(id) = &question_id {
+                                self.resolve_question(id, Some(&response));
+                            }
                             // Create a human.response event to inject into the bus
                             response_event = Some(Event::new("human.response", &response));
                         }
@@ -2034,6 +2288,11 @@ impl EventLoop {
                                 timeout_secs = robot_service.timeout_secs(),
                                 "Human response timeout — continuing without response"
                             );
+                            if let Some(id) = &question_id {
+                                self.resolve_question(id, None);
+                            }
+                            // Let the agent know its question went unanswered
+                            response_event = Some(Event::new("human.timeout", &payload));
                         }
                         Err(e) => {
                             warn!(
@@ -2074,11 +2333,19 @@ impl EventLoop {
             self.bus.publish(event);
         }
 
-        // Publish human.response event if one was received during blocking
+        self.asked_questions.extend(asked_question);
+
+        // Questions this loop asked earlier that timed out while nobody was waiting
+        for timeout in overdue_timeouts {
+            info!(payload = %timeout.payload, "Publishing human.timeout for overdue question");
+            self.bus.publish(timeout);
+        }
+
+        // Publish human.response (or human.timeout) event from the blocking wait
         if let Some(response) = response_event {
             info!(
                 topic = %response.topic,
-                "Publishing human.response event from robot service"
+                "Publishing human response event from robot service"
             );
             self.bus.publish(response);
         }
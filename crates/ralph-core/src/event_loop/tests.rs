@@ -35,7 +35,9 @@ hats:
 
 #[test]
 fn test_guidance_persists_across_iterations_solo_mode() {
-    let config = RalphConfig::default();
+    let dir = tempfile::tempdir().unwrap();
+    let mut config = RalphConfig::default();
+    config.core.workspace_root = dir.path().to_path_buf();
     let mut event_loop = EventLoop::new(config);
     let ralph_id = HatId::new("ralph");
 
@@ -73,7 +75,9 @@ hats:
     triggers: ["task.start"]
     publishes: ["task.plan"]
 "#;
-    let config: RalphConfig = serde_yaml::from_str(yaml).unwrap();
+    let dir = tempfile::tempdir().unwrap();
+    let mut config: RalphConfig = serde_yaml::from_str(yaml).unwrap();
+    config.core.workspace_root = dir.path().to_path_buf();
     let mut event_loop = EventLoop::new(config);
     let ralph_id = HatId::new("ralph");
 
@@ -136,6 +140,272 @@ core:
     );
 }
 
+#[test]
+fn test_steering_command_rendered_and_applied() {
+    use crate::task::Task;
+    use crate::task_store::TaskStore;
+
+    let dir = tempfile::tempdir().unwrap();
+    let tasks_path = dir.path().join(".ralph/agent/tasks.jsonl");
+    let mut store = TaskStore::load(&tasks_path).unwrap();
+    let task_id = store
+        .with_exclusive_lock(|s| s.add(Task::new("Refactor auth".to_string(), 3)).id.clone())
+        .unwrap();
+
+    let yaml = format!(
+        r#"
+core:
+  scratchpad: "{}"
+"#,
+        dir.path().join("scratchpad.md").display()
+    );
+    let mut config: RalphConfig = serde_yaml::from_str(&yaml).unwrap();
+    config.core.workspace_root = dir.path().to_path_buf();
+    let mut event_loop = EventLoop::new(config);
+    let ralph_id = HatId::new("ralph");
+
+    event_loop.bus.publish(Event::new(
+        "human.guidance",
+        format!(r#"{{"command": "change_priority", "task_id": "{task_id}", "priority": 1}}"#),
+    ));
+    event_loop.bus.publish(Event::new(
+        "human.guidance",
+        r#"{"command": "focus_on", "path": "src/auth"}"#,
+    ));
+
+    let prompt = event_loop.build_prompt(&ralph_id).unwrap();
+    assert!(prompt.contains("Focus this work on `src/auth`"));
+    assert!(prompt.contains(&format!("Task {task_id} has been reprioritized to P1")));
+    assert!(
+        !prompt.contains(r#""command""#),
+        "Raw JSON should not leak into the prompt"
+    );
+
+    let store = TaskStore::load(&tasks_path).unwrap();
+    assert_eq!(store.get(&task_id).unwrap().priority, 1);
+}
+
+#[test]
+fn test_steering_change_priority_for_missing_task() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut config = RalphConfig::default();
+    config.core.workspace_root = dir.path().to_path_buf();
+    let mut event_loop = EventLoop::new(config);
+    let ralph_id = HatId::new("ralph");
+
+    event_loop.bus.publish(Event::new(
+        "human.guidance",
+        r#"{"command": "change_priority", "task_id": "task-404", "priority": 1}"#,
+    ));
+
+    let prompt = event_loop.build_prompt(&ralph_id).unwrap();
+    assert!(prompt.contains("Could not reprioritize task-404: task not found"));
+    assert!(
+        !prompt.contains("has been reprioritized"),
+        "The agent must not be told about a change that wasn't applied"
+    );
+}
+
+#[test]
+fn test_guidance_retract_drops_unconsumed_guidance() {
+    let dir = tempfile::tempdir().unwrap();
+    let scratchpad_path = dir.path().join("scratchpad.md");
+    let yaml = format!(
+        r#"
+core:
+  scratchpad: "{}"
+"#,
+        scratchpad_path.display()
+    );
+    let config: RalphConfig = serde_yaml::from_str(&yaml).unwrap();
+    let mut event_loop = EventLoop::new(config);
+    let ralph_id = HatId::new("ralph");
+
+    event_loop
+        .bus
+        .publish(Event::new("human.guidance", "Keep the parser"));
+    event_loop
+        .bus
+        .publish(Event::new("human.guidance", "Delete the parser"));
+    event_loop
+        .bus
+        .publish(Event::new("human.guidance.retract", ""));
+
+    let prompt = event_loop.build_prompt(&ralph_id).unwrap();
+    assert!(prompt.contains("Keep the parser"));
+    assert!(!prompt.contains("Delete the parser"));
+
+    let scratchpad = std::fs::read_to_string(&scratchpad_path).unwrap();
+    assert!(
+        !scratchpad.contains("Delete the parser"),
+        "Retracted guidance should never be persisted"
+    );
+}
+
+#[test]
+fn test_guidance_retract_ignores_delivered_guidance() {
+    let dir = tempfile::tempdir().unwrap();
+    let context = LoopContext::primary(dir.path().to_path_buf());
+    context.ensure_directories().unwrap();
+    std::fs::write(
+        context.current_events_marker(),
+        ".ralph/events-20260130-100000.jsonl",
+    )
+    .unwrap();
+    let mut event_loop = EventLoop::with_context(RalphConfig::default(), context.clone());
+    let ralph_id = HatId::new("ralph");
+
+    event_loop
+        .bus
+        .publish(Event::new("human.guidance", "Rewrite everything in Go"));
+    let prompt = event_loop.build_prompt(&ralph_id).unwrap();
+    assert!(prompt.contains("Rewrite everything in Go"));
+
+    // The agent has seen it, so the retract can't take it back
+    event_loop
+        .bus
+        .publish(Event::new("human.guidance.retract", ""));
+    let prompt = event_loop.build_prompt(&ralph_id).unwrap();
+    assert!(prompt.contains("Rewrite everything in Go"));
+
+    // Both guidance-topic events are recorded as consumed for this run
+    let marker: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(context.guidance_marker_path()).unwrap())
+            .unwrap();
+    assert_eq!(marker["events"], ".ralph/events-20260130-100000.jsonl");
+    assert_eq!(marker["consumed"], 2);
+}
+
+#[test]
+fn test_pinned_files_injected_into_prompt() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::create_dir_all(dir.path().join(".ralph/agent")).unwrap();
+    std::fs::write(dir.path().join("auth.rs"), "fn login() {}").unwrap();
+
+    let mut config = RalphConfig::default();
+    config.core.workspace_root = dir.path().to_path_buf();
+    let mut event_loop = EventLoop::new(config);
+    let ralph_id = HatId::new("ralph");
+
+    let prompt = event_loop.build_prompt(&ralph_id).unwrap();
+    assert!(!prompt.contains("<pinned-files>"));
+
+    // The manifest is picked up on the next prompt without restarting
+    std::fs::write(dir.path().join(".ralph/agent/pinned-files"), "auth.rs\n").unwrap();
+    let prompt = event_loop.build_prompt(&ralph_id).unwrap();
+    assert!(prompt.contains("<file path=\"auth.rs\">\nfn login() {}\n</file>"));
+}
+
+#[test]
+fn test_question_with_suggestions_lists_past_answers() {
+    let dir = tempfile::tempdir().unwrap();
+    let context = LoopContext::primary(dir.path().to_path_buf());
+    let event_loop = EventLoop::with_context(RalphConfig::default(), context.clone());
+
+    // No history yet: the question is sent unchanged
+    let question = "Should I deploy to production now?";
+    assert_eq!(event_loop.question_with_suggestions(question), question);
+
+    std::fs::create_dir_all(context.agent_dir()).unwrap();
+    let mut store = QuestionStore::load(&context.questions_path()).unwrap();
+    store
+        .with_exclusive_lock(|s| {
+            let now = chrono::Utc::now();
+            let id = s
+                .add(Question::new("Should I deploy to production?", now, 60))
+                .id
+                .clone();
+            s.answer(&id, "No, staging first", now);
+        })
+        .unwrap();
+
+    assert_eq!(
+        event_loop.question_with_suggestions(question),
+        "Should I deploy to production now?\n\n\
+         Previous answers to similar questions:\n- No, staging first"
+    );
+}
+
+#[cfg(unix)]
+#[test]
+fn test_worktree_questions_are_recorded_in_main_repo() {
+    let dir = tempfile::tempdir().unwrap();
+    let repo_root = dir.path().to_path_buf();
+    let primary = LoopContext::primary(repo_root.clone());
+    let worktree = LoopContext::worktree(
+        "loop-a",
+        repo_root.join(".worktrees/loop-a"),
+        repo_root.clone(),
+    );
+    worktree.ensure_directories().unwrap();
+    worktree.setup_worktree_symlinks().unwrap();
+
+    let mut config = RalphConfig::default();
+    config.core.preset = Some("feature".to_string());
+    let mut event_loop = EventLoop::with_context(config, worktree.clone());
+    event_loop.state.last_hat = Some(HatId::new("builder"));
+
+    let id = event_loop.record_question("Which DB?", 60).unwrap();
+
+    // The question outlives the worktree: it lives in the main repo's store
+    std::fs::remove_dir_all(worktree.workspace()).unwrap();
+    let store = QuestionStore::load(&primary.questions_path()).unwrap();
+    let question = store.get(&id).unwrap();
+    assert_eq!(question.loop_id.as_deref(), Some("loop-a"));
+    assert_eq!(question.hat.as_deref(), Some("builder"));
+    assert_eq!(question.preset.as_deref(), Some("feature"));
+
+    let stats = store.stats();
+    assert_eq!(stats.per_loop["loop-a"], 1);
+    assert_eq!(stats.per_hat["builder"], 1);
+}
+
+#[test]
+fn test_overdue_questions_emit_timeout_events() {
+    let dir = tempfile::tempdir().unwrap();
+    let context = LoopContext::primary(dir.path().to_path_buf());
+    let mut event_loop = EventLoop::with_context(RalphConfig::default(), context.clone());
+    assert!(event_loop.expire_overdue_questions().is_empty());
+
+    std::fs::create_dir_all(context.agent_dir()).unwrap();
+    let mut store = QuestionStore::load(&context.questions_path()).unwrap();
+    let (own, stale) = store
+        .with_exclusive_lock(|s| {
+            let asked = chrono::Utc::now() - chrono::Duration::minutes(10);
+            let own = s
+                .add(Question::new("Merge the auth branch?", asked, 60))
+                .id
+                .clone();
+            let stale = s
+                .add(Question::new("Question from an earlier run?", asked, 60))
+                .id
+                .clone();
+            s.add(Question::new(
+                "Still waiting on this?",
+                chrono::Utc::now(),
+                3600,
+            ));
+            (own, stale)
+        })
+        .unwrap();
+    event_loop.asked_questions.push(own);
+
+    // Only the question this loop asked produces a timeout event
+    let timeouts = event_loop.expire_overdue_questions();
+    assert_eq!(timeouts.len(), 1);
+    assert_eq!(timeouts[0].topic.as_str(), "human.timeout");
+    assert_eq!(timeouts[0].payload, "Merge the auth branch?");
+
+    // The stale question is still expired in the store, and nothing repeats
+    assert!(event_loop.expire_overdue_questions().is_empty());
+    let store = QuestionStore::load(&context.questions_path()).unwrap();
+    assert_eq!(
+        store.get(&stale).unwrap().status,
+        crate::question_store::QuestionStatus::Expired
+    );
+    assert_eq!(store.pending().len(), 1);
+}
+
 #[test]
 fn test_guidance_appends_to_existing_scratchpad() {
     let dir = tempfile::tempdir().unwrap();
@@ -82,18 +82,58 @@ where
 }
 
 /// A simplified event for reading from JSONL.
+///
+/// Also accepts the legacy `{"type": ..., "timestamp": ...}` shape; `topic`
+/// and `ts` take precedence when both are present, and events are always
+/// serialized with `topic` and `ts`.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(try_from = "RawEvent")]
 pub struct Event {
     pub topic: String,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "deserialize_flexible_payload"
-    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub payload: Option<String>,
     pub ts: String,
 }
 
+/// On-disk shape of [`Event`], including the legacy field names.
+#[derive(Deserialize)]
+struct RawEvent {
+    topic: Option<String>,
+    #[serde(rename = "type")]
+    legacy_topic: Option<serde_json::Value>,
+    #[serde(default, deserialize_with = "deserialize_flexible_payload")]
+    payload: Option<String>,
+    ts: Option<String>,
+    #[serde(rename = "timestamp")]
+    legacy_ts: Option<serde_json::Value>,
+}
+
+impl TryFrom<RawEvent> for Event {
+    type Error = String;
+
+    fn try_from(raw: RawEvent) -> Result<Self, Self::Error> {
+        Ok(Self {
+            topic: prefer_field(raw.topic, raw.legacy_topic.as_ref(), "topic")?,
+            payload: raw.payload,
+            ts: prefer_field(raw.ts, raw.legacy_ts.as_ref(), "ts")?,
+        })
+    }
+}
+
+/// Returns the canonical field value, falling back to a legacy string value.
+///
+/// Legacy values that aren't strings are ignored, matching how unknown keys
+/// were skipped before legacy names were recognized.
+pub(crate) fn prefer_field(
+    value: Option<String>,
+    legacy: Option<&serde_json::Value>,
+    name: &str,
+) -> Result<String, String> {
+    value
+        .or_else(|| legacy.and_then(|v| v.as_str()).map(str::to_string))
+        .ok_or_else(|| format!("missing field `{name}`"))
+}
+
 /// Reads new events from `.ralph/events.jsonl` since last read.
 pub struct EventReader {
     path: PathBuf,
@@ -116,6 +156,9 @@ impl EventReader {
     /// validation - the caller can emit `event.malformed` events and
     /// track consecutive failures.
     ///
+    /// If the file is now shorter than the last read position (it was
+    /// cleared or replaced), reading restarts from the beginning.
+    ///
     /// # Errors
     ///
     /// Returns an error if the file cannot be opened or read.
@@ -125,6 +168,16 @@ impl EventReader {
         }
 
         let mut file = File::open(&self.path)?;
+        let len = file.metadata()?.len();
+        if len < self.position {
+            warn!(
+                path = %self.path.display(),
+                position = self.position,
+                len,
+                "Events file shrank since last read, re-reading from start"
+            );
+            self.position = 0;
+        }
         file.seek(SeekFrom::Start(self.position))?;
 
         let reader = BufReader::new(file);
@@ -306,6 +359,32 @@ mod tests {
         assert_eq!(result.events.len(), 1);
     }
 
+    #[test]
+    fn test_truncated_file_is_reread_from_start() {
+        let mut file = NamedTempFile::new().unwrap();
+        writeln!(
+            file,
+            r#"{{"topic":"build.task","payload":"a long payload to pad the file","ts":"2024-01-01T00:00:00Z"}}"#
+        )
+        .unwrap();
+        file.flush().unwrap();
+
+        let mut reader = EventReader::new(file.path());
+        assert_eq!(reader.read_new_events().unwrap().events.len(), 1);
+
+        // Simulate `ralph events --clear` followed by a fresh `ralph emit`
+        std::fs::write(
+            file.path(),
+            "{\"topic\":\"build.done\",\"ts\":\"2024-01-01T00:00:01Z\"}\n",
+        )
+        .unwrap();
+
+        let result = reader.read_new_events().unwrap();
+        assert_eq!(result.events.len(), 1);
+        assert_eq!(result.events[0].topic, "build.done");
+        assert!(result.malformed.is_empty());
+    }
+
     #[test]
     fn test_structured_payload_as_object() {
         // Test that JSON objects in payload field are converted to strings
@@ -378,6 +457,77 @@ mod tests {
         assert_eq!(result.events[2].payload, None);
     }
 
+    #[test]
+    fn test_legacy_type_timestamp_format() {
+        let mut file = NamedTempFile::new().unwrap();
+        writeln!(
+            file,
+            r#"{{"type":"human.interact","payload":"Ship it?","timestamp":"2024-01-01T00:00:00Z"}}"#
+        )
+        .unwrap();
+        writeln!(
+            file,
+            r#"{{"topic":"build.done","ts":"2024-01-01T00:00:01Z"}}"#
+        )
+        .unwrap();
+        file.flush().unwrap();
+
+        let mut reader = EventReader::new(file.path());
+        let result = reader.read_new_events().unwrap();
+
+        assert!(result.malformed.is_empty());
+        assert_eq!(result.events.len(), 2);
+        assert_eq!(result.events[0].topic, "human.interact");
+        assert_eq!(result.events[0].ts, "2024-01-01T00:00:00Z");
+        assert_eq!(result.events[0].payload, Some("Ship it?".to_string()));
+        assert_eq!(result.events[1].topic, "build.done");
+    }
+
+    #[test]
+    fn test_canonical_fields_win_over_legacy_fields() {
+        let mut file = NamedTempFile::new().unwrap();
+        writeln!(
+            file,
+            r#"{{"topic":"build.done","type":"legacy.topic","ts":"2024-01-01T00:00:01Z","timestamp":"1999-01-01T00:00:00Z"}}"#
+        )
+        .unwrap();
+        // Non-string legacy values are ignored, as unknown keys always were
+        writeln!(
+            file,
+            r#"{{"topic":"build.task","type":{{"kind":"x"}},"ts":"2024-01-01T00:00:02Z","timestamp":42}}"#
+        )
+        .unwrap();
+        file.flush().unwrap();
+
+        let mut reader = EventReader::new(file.path());
+        let result = reader.read_new_events().unwrap();
+
+        assert!(result.malformed.is_empty());
+        assert_eq!(result.events.len(), 2);
+        assert_eq!(result.events[0].topic, "build.done");
+        assert_eq!(result.events[0].ts, "2024-01-01T00:00:01Z");
+        assert_eq!(result.events[1].topic, "build.task");
+        assert_eq!(result.events[1].ts, "2024-01-01T00:00:02Z");
+    }
+
+    #[test]
+    fn test_missing_topic_is_malformed() {
+        let mut file = NamedTempFile::new().unwrap();
+        writeln!(
+            file,
+            r#"{{"payload":"orphan","ts":"2024-01-01T00:00:00Z"}}"#
+        )
+        .unwrap();
+        file.flush().unwrap();
+
+        let mut reader = EventReader::new(file.path());
+        let result = reader.read_new_events().unwrap();
+
+        assert!(result.events.is_empty());
+        assert_eq!(result.malformed.len(), 1);
+        assert!(result.malformed[0].error.contains("missing field `topic`"));
+    }
+
     #[test]
     fn test_nested_object_payload() {
         // Test deeply nested objects are handled correctly
@@ -0,0 +1,121 @@
+//! Atomic batch writes to `.ralph/events.jsonl`.
+//!
+//! External tools (CI hooks, deploy scripts) can feed signals such as
+//! `deploy.finished` into a running loop. A batch is validated up front and
+//! appended with a single write, so either every event lands or none do.
+//!
+//! The write happens under the `events.jsonl.lock` file lock, which only
+//! orders batches against each other. The event logger and single-event
+//! `ralph emit` append without taking it, so nothing stops their lines from
+//! mixing with a batch.
+
+use crate::event_reader::Event;
+use crate::file_lock::FileLock;
+use std::fs::OpenOptions;
+use std::io::{self, Write};
+use std::path::Path;
+
+/// Checks that an event is well-formed enough to be routed.
+///
+/// This is a syntax check only: topics must be concrete dot-separated names
+/// (no wildcards, whitespace, or empty segments) and timestamps must be
+/// RFC 3339. Topics aren't checked against the events declared in config,
+/// and payloads aren't checked at all.
+pub fn validate_event(event: &Event) -> Result<(), String> {
+    let topic = event.topic.as_str();
+    if topic.is_empty() {
+        return Err("topic must not be empty".to_string());
+    }
+    if topic.contains('*') {
+        return Err(format!("topic '{topic}' must not contain wildcards"));
+    }
+    if topic.chars().any(char::is_whitespace) {
+        return Err(format!("topic '{topic}' must not contain whitespace"));
+    }
+    if topic.split('.').any(str::is_empty) {
+        return Err(format!("topic '{topic}' has an empty segment"));
+    }
+    if chrono::DateTime::parse_from_rfc3339(&event.ts).is_err() {
+        return Err(format!("ts '{}' is not an RFC 3339 timestamp", event.ts));
+    }
+    Ok(())
+}
+
+/// Appends a batch of events to the JSONL file at `path`.
+///
+/// All events are validated before anything is written; the first invalid
+/// event aborts the batch with an `InvalidInput` error naming its index.
+pub fn append_events(path: &Path, events: &[Event]) -> io::Result<()> {
+    let mut buf = String::new();
+    for (index, event) in events.iter().enumerate() {
+        validate_event(event).map_err(|e| {
+            io::Error::new(io::ErrorKind::InvalidInput, format!("event {index}: {e}"))
+        })?;
+        let line = serde_json::to_string(event)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        buf.push_str(&line);
+        buf.push('\n');
+    }
+    if buf.is_empty() {
+        return Ok(());
+    }
+
+    let lock = FileLock::new(path)?;
+    let _guard = lock.exclusive()?;
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    file.write_all(buf.as_bytes())?;
+    file.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EventReader;
+    use tempfile::TempDir;
+
+    fn event(topic: &str) -> Event {
+        Event {
+            topic: topic.to_string(),
+            payload: Some("payload".to_string()),
+            ts: "2025-01-01T00:00:00Z".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_validate_event_rejects_bad_topics() {
+        assert!(validate_event(&event("deploy.finished")).is_ok());
+        assert!(validate_event(&event("")).is_err());
+        assert!(validate_event(&event("deploy.*")).is_err());
+        assert!(validate_event(&event("deploy finished")).is_err());
+        assert!(validate_event(&event("deploy..finished")).is_err());
+
+        let mut bad_ts = event("deploy.finished");
+        bad_ts.ts = "yesterday".to_string();
+        assert!(validate_event(&bad_ts).is_err());
+    }
+
+    #[test]
+    fn test_append_events_writes_batch() {
+        let tmp = TempDir::new().unwrap();
+        let path = tmp.path().join("events.jsonl");
+
+        append_events(&path, &[event("deploy.finished"), event("alert.fired")]).unwrap();
+
+        let mut reader = EventReader::new(&path);
+        let result = reader.read_new_events().unwrap();
+        let topics: Vec<_> = result.events.iter().map(|e| e.topic.as_str()).collect();
+        assert_eq!(topics, vec!["deploy.finished", "alert.fired"]);
+    }
+
+    #[test]
+    fn test_append_events_is_all_or_nothing() {
+        let tmp = TempDir::new().unwrap();
+        let path = tmp.path().join("events.jsonl");
+
+        let err =
+            append_events(&path, &[event("deploy.finished"), event("bad topic")]).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+        assert!(err.to_string().starts_with("event 1:"));
+        assert!(!path.exists());
+    }
+}
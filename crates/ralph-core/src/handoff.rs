@@ -5,6 +5,7 @@
 //! - What remains (open tasks with dependencies)
 //! - Context (last commit, branch, key files)
 //! - Ready-to-paste prompt for next session
+//! - Human handoff notes from `.ralph/agent/handoff-notes.md`, if present
 //!
 //! This enables clean session boundaries and seamless handoffs between
 //! Ralph loops, supporting the "land the plane" pattern.
@@ -94,6 +95,9 @@ impl HandoffWriter {
             chrono::Utc::now().format("%Y-%m-%d %H:%M:%S UTC")
         ));
 
+        // Human notes go first so the next person sees them before anything else
+        self.write_human_notes(&mut content);
+
         // Git context section
         content.push_str("## Git Context\n\n");
         self.write_git_context(&mut content);
@@ -113,6 +117,21 @@ impl HandoffWriter {
         content
     }
 
+    /// Writes the human-authored handoff notes, if any.
+    fn write_human_notes(&self, content: &mut String) {
+        let Ok(notes) = std::fs::read_to_string(self.context.handoff_notes_path()) else {
+            return;
+        };
+        let notes = notes.trim();
+        if notes.is_empty() {
+            return;
+        }
+
+        content.push_str("## Human Notes\n\n");
+        content.push_str(notes);
+        content.push_str("\n\n");
+    }
+
     /// Writes git context (branch, commit, status).
     fn write_git_context(&self, content: &mut String) {
         let workspace = self.context.workspace();
@@ -325,6 +344,28 @@ mod tests {
         assert!(content.contains("## Next Session"));
     }
 
+    #[test]
+    fn test_handoff_includes_human_notes() {
+        let (_temp, ctx) = setup_test_context();
+        let writer = HandoffWriter::new(ctx.clone());
+
+        writer.write("Test prompt").unwrap();
+        let content = fs::read_to_string(ctx.handoff_path()).unwrap();
+        assert!(!content.contains("## Human Notes"));
+
+        fs::write(
+            ctx.handoff_notes_path(),
+            "Watch the flaky auth test.\nDecide on retry policy.\n",
+        )
+        .unwrap();
+        writer.write("Test prompt").unwrap();
+
+        let content = fs::read_to_string(ctx.handoff_path()).unwrap();
+        let notes = content.find("## Human Notes").unwrap();
+        assert!(notes < content.find("## Git Context").unwrap());
+        assert!(content.contains("Watch the flaky auth test.\nDecide on retry policy.\n\n"));
+    }
+
     #[test]
     fn test_handoff_with_no_tasks() {
         let (_temp, ctx) = setup_test_context();
@@ -0,0 +1,190 @@
+//! Hat activity analytics derived from the event log.
+//!
+//! A hat is considered active from the first event that triggers it until an
+//! event triggers a different hat (or the log ends). The `triggered` field is
+//! used rather than `hat`, because in multi-hat mode events are published by
+//! Ralph as coordinator and `hat` is always `ralph`.
+
+use crate::EventRecord;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
+
+/// Time and activation totals for a single hat.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct HatActivity {
+    /// Hat ID.
+    pub hat: String,
+
+    /// Milliseconds the hat was active.
+    pub active_ms: u64,
+
+    /// Fraction of total active time spent in this hat (0.0 - 1.0).
+    pub share: f64,
+
+    /// Number of times the hat was entered from another hat (or at start).
+    pub activations: usize,
+}
+
+/// Per-hat time and transition counts for a run.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct HatAnalytics {
+    /// Per-hat activity, most active first.
+    pub hats: Vec<HatActivity>,
+
+    /// Transition matrix: `matrix[from][to]` is how often `from` handed off to `to`.
+    pub matrix: BTreeMap<String, BTreeMap<String, usize>>,
+
+    /// Total number of hat transitions.
+    pub transitions: usize,
+
+    /// Total active milliseconds across all hats.
+    pub total_ms: u64,
+}
+
+impl HatAnalytics {
+    /// Computes hat analytics from event records in log order.
+    ///
+    /// Records with unparseable timestamps still count towards transitions but
+    /// do not contribute to time totals.
+    pub fn from_records(records: &[EventRecord]) -> Self {
+        let mut active_ms: HashMap<String, u64> = HashMap::new();
+        let mut activations: HashMap<String, usize> = HashMap::new();
+        let mut matrix: BTreeMap<String, BTreeMap<String, usize>> = BTreeMap::new();
+        let mut transitions = 0;
+
+        let mut current: Option<(String, Option<DateTime<Utc>>)> = None;
+        let mut last_ts = None;
+
+        for record in records {
+            let ts = DateTime::parse_from_rfc3339(&record.ts)
+                .ok()
+                .map(|t| t.with_timezone(&Utc));
+            if ts.is_some() {
+                last_ts = ts;
+            }
+
+            let Some(hat) = record.triggered.as_deref() else {
+                continue;
+            };
+            if current.as_ref().is_some_and(|(active, _)| active == hat) {
+                continue;
+            }
+
+            if let Some((previous, since)) = current.take() {
+                *active_ms.entry(previous.clone()).or_default() += elapsed_ms(since, ts);
+                *matrix
+                    .entry(previous)
+                    .or_default()
+                    .entry(hat.to_string())
+                    .or_default() += 1;
+                transitions += 1;
+            }
+            *activations.entry(hat.to_string()).or_default() += 1;
+            current = Some((hat.to_string(), ts));
+        }
+
+        if let Some((previous, since)) = current {
+            *active_ms.entry(previous).or_default() += elapsed_ms(since, last_ts);
+        }
+
+        let total_ms: u64 = active_ms.values().sum();
+        let mut hats: Vec<HatActivity> = activations
+            .into_iter()
+            .map(|(hat, activations)| {
+                let ms = active_ms.get(&hat).copied().unwrap_or(0);
+                HatActivity {
+                    share: if total_ms == 0 {
+                        0.0
+                    } else {
+                        ms as f64 / total_ms as f64
+                    },
+                    active_ms: ms,
+                    hat,
+                    activations,
+                }
+            })
+            .collect();
+        hats.sort_by(|a, b| b.active_ms.cmp(&a.active_ms).then(a.hat.cmp(&b.hat)));
+
+        Self {
+            hats,
+            matrix,
+            transitions,
+            total_ms,
+        }
+    }
+}
+
+/// Milliseconds between two optional timestamps, or 0 if either is missing.
+fn elapsed_ms(from: Option<DateTime<Utc>>, to: Option<DateTime<Utc>>) -> u64 {
+    match (from, to) {
+        (Some(from), Some(to)) => u64::try_from((to - from).num_milliseconds()).unwrap_or(0),
+        _ => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(ts: &str, topic: &str, triggered: Option<&str>) -> EventRecord {
+        EventRecord {
+            ts: ts.to_string(),
+            iteration: 1,
+            hat: "ralph".to_string(),
+            topic: topic.to_string(),
+            triggered: triggered.map(str::to_string),
+            payload: String::new(),
+            blocked_count: None,
+        }
+    }
+
+    #[test]
+    fn test_time_and_transitions_per_hat() {
+        let records = vec![
+            record("2025-01-15T10:00:00Z", "task.start", Some("planner")),
+            record("2025-01-15T10:01:00Z", "build.task", Some("builder")),
+            record("2025-01-15T10:02:00Z", "build.task", Some("builder")),
+            record("2025-01-15T10:05:00Z", "build.failed", Some("fixer")),
+            record("2025-01-15T10:06:00Z", "fix.done", Some("builder")),
+            record("2025-01-15T10:10:00Z", "LOOP_COMPLETE", None),
+        ];
+
+        let analytics = HatAnalytics::from_records(&records);
+
+        assert_eq!(analytics.total_ms, 10 * 60 * 1000);
+        assert_eq!(analytics.transitions, 3);
+
+        let builder = &analytics.hats[0];
+        assert_eq!(builder.hat, "builder");
+        assert_eq!(builder.active_ms, 8 * 60 * 1000);
+        assert_eq!(builder.activations, 2);
+        assert!((builder.share - 0.8).abs() < f64::EPSILON);
+
+        assert_eq!(analytics.matrix["planner"]["builder"], 1);
+        assert_eq!(analytics.matrix["builder"]["fixer"], 1);
+        assert_eq!(analytics.matrix["fixer"]["builder"], 1);
+    }
+
+    #[test]
+    fn test_unparseable_timestamps_count_transitions_only() {
+        let records = vec![
+            record("not-a-time", "task.start", Some("planner")),
+            record("2025-01-15T10:01:00Z", "build.task", Some("builder")),
+            record("2025-01-15T10:03:00Z", "build.done", None),
+        ];
+
+        let analytics = HatAnalytics::from_records(&records);
+
+        assert_eq!(analytics.transitions, 1);
+        assert_eq!(analytics.total_ms, 2 * 60 * 1000);
+        assert_eq!(analytics.hats[0].hat, "builder");
+        assert_eq!(analytics.hats[1].active_ms, 0);
+    }
+
+    #[test]
+    fn test_empty_log() {
+        assert_eq!(HatAnalytics::from_records(&[]), HatAnalytics::default());
+    }
+}
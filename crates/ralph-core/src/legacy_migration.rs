@@ -0,0 +1,246 @@
+//! Migration of legacy `.agent/` workspaces to the `.ralph/` layout.
+//!
+//! Older Ralph versions kept all runtime state in `.agent/` at the workspace
+//! root. Current versions split it between `.ralph/agent/` (scratchpad,
+//! memories, tasks, summaries) and `.ralph/` (event logs).
+//!
+//! Migration is two-phase so callers can show a dry-run report first:
+//!
+//! ```ignore
+//! let plan = MigrationPlan::for_workspace(root)?;
+//! for step in &plan.steps { println!("{} -> {}", step.from.display(), step.to.display()); }
+//! plan.apply()?;
+//! ```
+
+use serde::Serialize;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Name of the legacy state directory.
+pub const LEGACY_AGENT_DIR: &str = ".agent";
+
+/// What happens to a single legacy file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MigrationAction {
+    /// The file is moved to its new location.
+    Move,
+    /// The destination already exists; the legacy file is left untouched.
+    SkipExisting,
+}
+
+/// A single file relocation within a migration plan.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct MigrationStep {
+    /// Source path under `.agent/`.
+    pub from: PathBuf,
+    /// Destination path under `.ralph/`.
+    pub to: PathBuf,
+    /// Whether the file will be moved or skipped.
+    pub action: MigrationAction,
+}
+
+/// The set of file moves needed to convert a legacy workspace.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct MigrationPlan {
+    /// Legacy directory being migrated.
+    pub legacy_dir: PathBuf,
+    /// Planned relocations, in a stable (sorted) order.
+    pub steps: Vec<MigrationStep>,
+}
+
+impl MigrationPlan {
+    /// Builds a migration plan for the workspace at `root`.
+    ///
+    /// Returns an empty plan if there's no `.agent/` directory. Event logs
+    /// (`events*.jsonl`) at the top of `.agent/` move to `.ralph/`; everything
+    /// else moves to `.ralph/agent/` keeping its relative path.
+    pub fn for_workspace(root: &Path) -> io::Result<Self> {
+        let legacy_dir = root.join(LEGACY_AGENT_DIR);
+        let mut plan = Self {
+            legacy_dir: legacy_dir.clone(),
+            steps: Vec::new(),
+        };
+        if !legacy_dir.is_dir() {
+            return Ok(plan);
+        }
+
+        let ralph_dir = root.join(".ralph");
+        let mut files = Vec::new();
+        collect_files(&legacy_dir, &mut files)?;
+        files.sort();
+
+        for from in files {
+            let relative = from
+                .strip_prefix(&legacy_dir)
+                .expect("collected files live under the legacy dir");
+            let to = if is_event_log(relative) {
+                ralph_dir.join(relative)
+            } else {
+                ralph_dir.join("agent").join(relative)
+            };
+            let action = if to.exists() {
+                MigrationAction::SkipExisting
+            } else {
+                MigrationAction::Move
+            };
+            plan.steps.push(MigrationStep { from, to, action });
+        }
+
+        Ok(plan)
+    }
+
+    /// Returns true if there is nothing to move.
+    pub fn is_noop(&self) -> bool {
+        self.steps
+            .iter()
+            .all(|s| s.action == MigrationAction::SkipExisting)
+    }
+
+    /// Executes the plan, moving files and pruning emptied legacy directories.
+    ///
+    /// Skipped files stay in `.agent/` so nothing is lost; the legacy directory
+    /// is only removed once it is empty. Returns the number of files moved.
+    pub fn apply(&self) -> io::Result<usize> {
+        let mut moved = 0;
+        for step in &self.steps {
+            if step.action != MigrationAction::Move {
+                continue;
+            }
+            if let Some(parent) = step.to.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            if fs::rename(&step.from, &step.to).is_err() {
+                // Rename fails across filesystems; fall back to copy + remove.
+                fs::copy(&step.from, &step.to)?;
+                fs::remove_file(&step.from)?;
+            }
+            moved += 1;
+        }
+
+        if self.legacy_dir.is_dir() {
+            prune_empty_dirs(&self.legacy_dir)?;
+        }
+        Ok(moved)
+    }
+}
+
+/// Returns true for top-level `events*.jsonl` files.
+fn is_event_log(relative: &Path) -> bool {
+    relative.components().count() == 1
+        && relative.extension().is_some_and(|ext| ext == "jsonl")
+        && relative
+            .file_stem()
+            .and_then(|n| n.to_str())
+            .is_some_and(|n| n.starts_with("events"))
+}
+
+fn collect_files(dir: &Path, files: &mut Vec<PathBuf>) -> io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let path = entry.path();
+        if entry.file_type()?.is_dir() {
+            collect_files(&path, files)?;
+        } else {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// Removes `dir` and any subdirectories that are empty. Returns true if `dir` was removed.
+fn prune_empty_dirs(dir: &Path) -> io::Result<bool> {
+    let mut empty = true;
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        if !(entry.file_type()?.is_dir() && prune_empty_dirs(&entry.path())?) {
+            empty = false;
+        }
+    }
+    if empty {
+        fs::remove_dir(dir)?;
+    }
+    Ok(empty)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn write(path: &Path, content: &str) {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    }
+
+    #[test]
+    fn test_plan_without_legacy_dir_is_empty() {
+        let tmp = TempDir::new().unwrap();
+        let plan = MigrationPlan::for_workspace(tmp.path()).unwrap();
+        assert!(plan.steps.is_empty());
+        assert!(plan.is_noop());
+    }
+
+    #[test]
+    fn test_plan_maps_state_and_event_logs() {
+        let tmp = TempDir::new().unwrap();
+        let root = tmp.path();
+        write(&root.join(".agent/scratchpad.md"), "# Scratchpad");
+        write(&root.join(".agent/events-20240101.jsonl"), "{}");
+        write(&root.join(".agent/metrics/state.json"), "{}");
+
+        let plan = MigrationPlan::for_workspace(root).unwrap();
+        let targets: Vec<_> = plan
+            .steps
+            .iter()
+            .map(|s| s.to.strip_prefix(root).unwrap().to_path_buf())
+            .collect();
+        assert_eq!(
+            targets,
+            vec![
+                PathBuf::from(".ralph/events-20240101.jsonl"),
+                PathBuf::from(".ralph/agent/metrics/state.json"),
+                PathBuf::from(".ralph/agent/scratchpad.md"),
+            ]
+        );
+        assert!(plan.steps.iter().all(|s| s.action == MigrationAction::Move));
+    }
+
+    #[test]
+    fn test_apply_moves_files_and_keeps_conflicts() {
+        let tmp = TempDir::new().unwrap();
+        let root = tmp.path();
+        write(&root.join(".agent/memories.md"), "legacy memories");
+        write(&root.join(".agent/tasks.jsonl"), "legacy tasks");
+        write(&root.join(".ralph/agent/memories.md"), "current memories");
+
+        let plan = MigrationPlan::for_workspace(root).unwrap();
+        assert!(!plan.is_noop());
+        assert_eq!(plan.apply().unwrap(), 1);
+
+        assert_eq!(
+            fs::read_to_string(root.join(".ralph/agent/tasks.jsonl")).unwrap(),
+            "legacy tasks"
+        );
+        assert_eq!(
+            fs::read_to_string(root.join(".ralph/agent/memories.md")).unwrap(),
+            "current memories"
+        );
+        // The conflicting legacy file is preserved
+        assert!(root.join(".agent/memories.md").exists());
+        assert!(!root.join(".agent/tasks.jsonl").exists());
+    }
+
+    #[test]
+    fn test_apply_removes_emptied_legacy_dir() {
+        let tmp = TempDir::new().unwrap();
+        let root = tmp.path();
+        write(&root.join(".agent/plans/plan.md"), "plan");
+
+        MigrationPlan::for_workspace(root).unwrap().apply().unwrap();
+
+        assert!(!root.join(".agent").exists());
+        assert!(root.join(".ralph/agent/plans/plan.md").exists());
+    }
+}
@@ -14,17 +14,21 @@
 mod cli_capture;
 mod config;
 pub mod diagnostics;
+pub mod event_archive;
 mod event_logger;
 mod event_loop;
 mod event_parser;
 mod event_reader;
+mod event_writer;
 pub mod file_lock;
 mod git_ops;
 mod handoff;
+mod hat_analytics;
 mod hat_registry;
 mod hatless_ralph;
 mod instructions;
 mod landing;
+pub mod legacy_migration;
 pub mod loop_completion;
 pub mod loop_context;
 pub mod loop_history;
@@ -35,14 +39,17 @@ mod memory;
 pub mod memory_parser;
 mod memory_store;
 pub mod merge_queue;
+pub mod pinned_files;
 pub mod planning_session;
 pub mod preflight;
+pub mod question_store;
 #[cfg(feature = "recording")]
 mod session_player;
 #[cfg(feature = "recording")]
 mod session_recorder;
 pub mod skill;
 pub mod skill_registry;
+pub mod steering;
 mod summary_writer;
 pub mod task;
 pub mod task_definition;
@@ -56,16 +63,21 @@ pub mod worktree;
 #[cfg(feature = "recording")]
 pub use cli_capture::{CliCapture, CliCapturePair};
 pub use config::{
-    CliConfig, ConfigError, CoreConfig, EventLoopConfig, EventMetadata, FeaturesConfig, HatBackend,
-    HatConfig, InjectMode, MemoriesConfig, MemoriesFilter, RalphConfig, SkillOverride,
-    SkillsConfig,
+    ArchiveConfig, CliConfig, ConfigError, CoreConfig, EventLoopConfig, EventMetadata,
+    FeaturesConfig, HatBackend, HatConfig, InjectMode, MemoriesConfig, MemoriesFilter, RalphConfig,
+    SkillOverride, SkillsConfig,
 };
 // Re-export loop_name types (also available via FeaturesConfig.loop_naming)
 pub use diagnostics::DiagnosticsCollector;
+pub use event_archive::{
+    ArchiveInfo, archive_event_log, archives_outside_retention, current_event_log,
+    enforce_retention, inactive_event_logs, list_archives,
+};
 pub use event_logger::{EventHistory, EventLogger, EventRecord};
 pub use event_loop::{EventLoop, LoopState, TerminationReason, UserPrompt};
 pub use event_parser::EventParser;
 pub use event_reader::{Event, EventReader, MalformedLine, ParseResult};
+pub use event_writer::{append_events, validate_event};
 pub use file_lock::{FileLock, LockGuard as FileLockGuard, LockedFile};
 pub use git_ops::{
     AutoCommitResult, GitOpsError, auto_commit_changes, clean_stashes, get_commit_summary,
@@ -73,16 +85,18 @@ pub use git_ops::{
     is_working_tree_clean, prune_remote_refs,
 };
 pub use handoff::{HandoffError, HandoffResult, HandoffWriter};
+pub use hat_analytics::{HatActivity, HatAnalytics};
 pub use hat_registry::HatRegistry;
 pub use hatless_ralph::{HatInfo, HatTopology, HatlessRalph};
 pub use instructions::InstructionBuilder;
 pub use landing::{LandingConfig, LandingError, LandingHandler, LandingResult};
+pub use legacy_migration::{MigrationAction, MigrationPlan, MigrationStep};
 pub use loop_completion::{CompletionAction, CompletionError, LoopCompletionHandler};
 pub use loop_context::LoopContext;
 pub use loop_history::{HistoryError, HistoryEvent, HistoryEventType, HistorySummary, LoopHistory};
 pub use loop_lock::{LockError, LockGuard, LockMetadata, LoopLock};
 pub use loop_name::{LoopNameGenerator, LoopNamingConfig};
-pub use loop_registry::{LoopEntry, LoopRegistry, RegistryError};
+pub use loop_registry::{LoopEntry, LoopHealth, LoopRegistry, RegistryError};
 pub use memory::{Memory, MemoryType};
 pub use memory_store::{
     DEFAULT_MEMORIES_PATH, MarkdownMemoryStore, format_memories_as_markdown, truncate_to_budget,
@@ -100,18 +114,20 @@ pub use preflight::{
     AcceptanceCriterion, CheckResult, CheckStatus, PreflightCheck, PreflightReport,
     PreflightRunner, extract_acceptance_criteria, extract_all_criteria, extract_criteria_from_file,
 };
+pub use question_store::{Question, QuestionStats, QuestionStatus, QuestionStore};
 #[cfg(feature = "recording")]
 pub use session_player::{PlayerConfig, ReplayMode, SessionPlayer, TimestampedRecord};
 #[cfg(feature = "recording")]
 pub use session_recorder::{Record, SessionRecorder};
 pub use skill::{SkillEntry, SkillFrontmatter, SkillSource, parse_frontmatter};
 pub use skill_registry::SkillRegistry;
+pub use steering::SteeringCommand;
 pub use summary_writer::SummaryWriter;
 pub use task::{Task, TaskStatus};
 pub use task_definition::{
     TaskDefinition, TaskDefinitionError, TaskSetup, TaskSuite, Verification,
 };
-pub use task_store::TaskStore;
+pub use task_store::{TaskOperation, TaskOperationResult, TaskStore};
 pub use text::{floor_char_boundary, truncate_with_ellipsis};
 pub use workspace::{
     CleanupPolicy, TaskWorkspace, VerificationResult, WorkspaceError, WorkspaceInfo,
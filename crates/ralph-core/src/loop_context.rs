@@ -9,6 +9,8 @@
 //! - **Primary loop**: Runs in the main workspace, paths resolve to standard locations
 //! - **Worktree loop**: Runs in a git worktree, paths resolve to worktree-local locations
 //! - **Shared memories**: Memories are symlinked in worktrees, pointing to main workspace
+//! - **Shared questions**: `human.interact` questions are symlinked too, so the
+//!   main workspace keeps every loop's questions after worktrees are removed
 //! - **Shared specs/tasks**: Specs and code tasks are symlinked in worktrees
 //!
 //! # Directory Structure
@@ -18,6 +20,7 @@
 //! .ralph/
 //! ├── agent/                    # Agent state (memories, tasks, scratchpad)
 //! │   ├── memories.md           # Symlinked in worktrees
+//! │   ├── questions.jsonl       # Symlinked in worktrees
 //! │   ├── tasks.jsonl           # Isolated per worktree
 //! │   ├── scratchpad.md         # Isolated per worktree
 //! │   └── context.md            # Worktree metadata (worktrees only)
@@ -181,6 +184,39 @@ impl LoopContext {
         self.agent_dir().join("tasks.jsonl")
     }
 
+    /// Path to the questions JSONL file.
+    ///
+    /// Records `human.interact` questions and their outcomes.
+    /// For worktree loops, this is a symlink to the main repo's questions.
+    pub fn questions_path(&self) -> PathBuf {
+        self.agent_dir().join("questions.jsonl")
+    }
+
+    /// Path to the main repository's questions file.
+    ///
+    /// Used to create symlinks in worktree loops.
+    pub fn main_questions_path(&self) -> PathBuf {
+        self.repo_root
+            .join(".ralph")
+            .join("agent")
+            .join("questions.jsonl")
+    }
+
+    /// Path to the consumed-guidance marker.
+    ///
+    /// Records how many guidance events from the current events file have
+    /// reached the agent, so `/undo` can tell whether anything is retractable.
+    pub fn guidance_marker_path(&self) -> PathBuf {
+        self.agent_dir().join("guidance-consumed")
+    }
+
+    /// Path to the pinned-files manifest.
+    ///
+    /// Lists repository paths inlined into every iteration's prompt.
+    pub fn pinned_files_path(&self) -> PathBuf {
+        self.agent_dir().join("pinned-files")
+    }
+
     /// Path to the scratchpad markdown file.
     ///
     /// Each loop has its own isolated scratchpad.
@@ -260,6 +296,14 @@ impl LoopContext {
         self.agent_dir().join("handoff.md")
     }
 
+    /// Path to the human-authored handoff notes.
+    ///
+    /// Free-form notes from whoever is monitoring the loop (what to watch,
+    /// open decisions). Copied into the generated handoff file.
+    pub fn handoff_notes_path(&self) -> PathBuf {
+        self.agent_dir().join("handoff-notes.md")
+    }
+
     /// Path to the diagnostics directory.
     ///
     /// Each loop has its own diagnostics output.
@@ -409,6 +453,44 @@ impl LoopContext {
         Ok(false)
     }
 
+    /// Creates the questions symlink in a worktree pointing to main repo.
+    ///
+    /// Questions asked by a worktree loop then outlive the worktree, and
+    /// `ralph tools interact` sees them from the main workspace.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(true)` - Symlink was created
+    /// - `Ok(false)` - Already exists or is primary loop
+    /// - `Err(_)` - Symlink creation failed
+    #[cfg(unix)]
+    pub fn setup_questions_symlink(&self) -> std::io::Result<bool> {
+        if self.is_primary {
+            return Ok(false);
+        }
+
+        let questions_path = self.questions_path();
+        let main_questions = self.main_questions_path();
+
+        // Skip if already exists (symlink or file)
+        if questions_path.exists() || questions_path.is_symlink() {
+            return Ok(false);
+        }
+
+        // Ensure parent directory exists
+        self.ensure_agent_dir()?;
+
+        // Create symlink
+        std::os::unix::fs::symlink(&main_questions, &questions_path)?;
+        Ok(true)
+    }
+
+    /// Creates the questions symlink in a worktree (non-Unix stub).
+    #[cfg(not(unix))]
+    pub fn setup_questions_symlink(&self) -> std::io::Result<bool> {
+        Ok(false)
+    }
+
     /// Creates the specs symlink in a worktree pointing to main repo.
     ///
     /// This allows worktree loops to access specs from the main repo,
@@ -558,13 +640,14 @@ Local state (scratchpad, runtime tasks, events) is isolated to this worktree.
         Ok(true)
     }
 
-    /// Sets up all worktree symlinks (memories, specs, code tasks).
+    /// Sets up all worktree symlinks (memories, questions, specs, code tasks).
     ///
     /// Convenience method that calls all setup_*_symlink methods.
     /// Only relevant for worktree loops - no-op for primary loops.
     #[cfg(unix)]
     pub fn setup_worktree_symlinks(&self) -> std::io::Result<()> {
         self.setup_memory_symlink()?;
+        self.setup_questions_symlink()?;
         self.setup_specs_symlink()?;
         self.setup_code_tasks_symlink()?;
         Ok(())
@@ -623,6 +706,14 @@ mod tests {
             ctx.tasks_path(),
             PathBuf::from("/project/.ralph/agent/tasks.jsonl")
         );
+        assert_eq!(
+            ctx.questions_path(),
+            PathBuf::from("/project/.ralph/agent/questions.jsonl")
+        );
+        assert_eq!(
+            ctx.pinned_files_path(),
+            PathBuf::from("/project/.ralph/agent/pinned-files")
+        );
         assert_eq!(
             ctx.scratchpad_path(),
             PathBuf::from("/project/.ralph/agent/scratchpad.md")
@@ -639,6 +730,10 @@ mod tests {
             ctx.handoff_path(),
             PathBuf::from("/project/.ralph/agent/handoff.md")
         );
+        assert_eq!(
+            ctx.handoff_notes_path(),
+            PathBuf::from("/project/.ralph/agent/handoff-notes.md")
+        );
         assert_eq!(ctx.specs_dir(), PathBuf::from("/project/.ralph/specs"));
         assert_eq!(ctx.code_tasks_dir(), PathBuf::from("/project/.ralph/tasks"));
         assert_eq!(
@@ -141,6 +141,50 @@ impl LoopEntry {
         // On non-Unix platforms, assume alive (conservative)
         true
     }
+
+    /// Returns when this loop last wrote an event.
+    ///
+    /// Uses the modification time of the loop's current events file (from
+    /// `.ralph/current-events`), or `None` if no events have been written.
+    pub fn last_event_at(&self) -> Option<DateTime<Utc>> {
+        let root = PathBuf::from(self.worktree_path.as_deref().unwrap_or(&self.workspace));
+        let marker = fs::read_to_string(root.join(".ralph/current-events")).ok()?;
+        let modified = fs::metadata(root.join(marker.trim()))
+            .ok()?
+            .modified()
+            .ok()?;
+        Some(modified.into())
+    }
+
+    /// Classifies the loop's health at `now`.
+    ///
+    /// A live loop is stalled when neither an event nor its start falls
+    /// within `stall_after`.
+    pub fn health(&self, now: DateTime<Utc>, stall_after: chrono::Duration) -> LoopHealth {
+        if !self.is_alive() {
+            return LoopHealth::Dead;
+        }
+        let last_progress = self
+            .last_event_at()
+            .map_or(self.started, |t| t.max(self.started));
+        if now - last_progress > stall_after {
+            LoopHealth::Stalled
+        } else {
+            LoopHealth::Running
+        }
+    }
+}
+
+/// Liveness and progress of a registered loop.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LoopHealth {
+    /// Process alive and writing events
+    Running,
+    /// Process alive but no events within the stall threshold
+    Stalled,
+    /// Process no longer exists
+    Dead,
 }
 
 /// The persisted registry data.
@@ -391,6 +435,39 @@ mod tests {
         assert_eq!(parts[0], "loop");
     }
 
+    #[test]
+    fn test_loop_entry_health() {
+        let temp_dir = TempDir::new().unwrap();
+        let ralph_dir = temp_dir.path().join(".ralph");
+        fs::create_dir_all(&ralph_dir).unwrap();
+
+        let mut entry = LoopEntry::with_workspace(
+            "test",
+            None::<String>,
+            temp_dir.path().display().to_string(),
+        );
+        let stall_after = chrono::Duration::minutes(10);
+        let now = Utc::now();
+
+        // Freshly started, no events yet
+        assert_eq!(entry.last_event_at(), None);
+        assert_eq!(entry.health(now, stall_after), LoopHealth::Running);
+
+        // Started long ago without events
+        entry.started = now - chrono::Duration::hours(1);
+        assert_eq!(entry.health(now, stall_after), LoopHealth::Stalled);
+
+        // A recent event counts as progress
+        fs::write(ralph_dir.join("current-events"), ".ralph/events-1.jsonl").unwrap();
+        fs::write(ralph_dir.join("events-1.jsonl"), "{}\n").unwrap();
+        assert!(entry.last_event_at().is_some());
+        assert_eq!(entry.health(now, stall_after), LoopHealth::Running);
+
+        // A process that no longer exists is dead
+        entry.pid = 999_999_999;
+        assert_eq!(entry.health(now, stall_after), LoopHealth::Dead);
+    }
+
     #[test]
     fn test_loop_entry_is_alive() {
         let entry = LoopEntry::new("test", None::<String>);
@@ -159,6 +159,12 @@ pub struct MergeEntry {
     /// When the loop was queued.
     pub queued_at: DateTime<Utc>,
 
+    /// When the most recent merge attempt started.
+    pub merge_started_at: Option<DateTime<Utc>>,
+
+    /// When the loop was merged, sent to review, or discarded.
+    pub resolved_at: Option<DateTime<Utc>>,
+
     /// PID of merge-ralph if merging.
     pub merge_pid: Option<u32>,
 
@@ -424,6 +430,8 @@ impl MergeQueue {
                     prompt: String::new(),
                     state: MergeState::Queued,
                     queued_at: event.ts,
+                    merge_started_at: None,
+                    resolved_at: None,
                     merge_pid: None,
                     merge_commit: None,
                     failure_reason: None,
@@ -435,22 +443,29 @@ impl MergeQueue {
                     entry.prompt = prompt.clone();
                     entry.state = MergeState::Queued;
                     entry.queued_at = event.ts;
+                    entry.merge_started_at = None;
+                    entry.resolved_at = None;
                 }
                 MergeEventType::Merging { pid } => {
                     entry.state = MergeState::Merging;
                     entry.merge_pid = Some(*pid);
+                    entry.merge_started_at = Some(event.ts);
+                    entry.resolved_at = None;
                 }
                 MergeEventType::Merged { commit } => {
                     entry.state = MergeState::Merged;
                     entry.merge_commit = Some(commit.clone());
+                    entry.resolved_at = Some(event.ts);
                 }
                 MergeEventType::NeedsReview { reason } => {
                     entry.state = MergeState::NeedsReview;
                     entry.failure_reason = Some(reason.clone());
+                    entry.resolved_at = Some(event.ts);
                 }
                 MergeEventType::Discarded { reason } => {
                     entry.state = MergeState::Discarded;
                     entry.discard_reason = reason.clone();
+                    entry.resolved_at = Some(event.ts);
                 }
             }
         }
@@ -819,6 +834,11 @@ mod tests {
         let entry = queue.get_entry("loop-abc").unwrap().unwrap();
         assert_eq!(entry.state, MergeState::Merged);
         assert_eq!(entry.merge_commit, Some("commit-sha-123".to_string()));
+
+        // Lifecycle timestamps are ordered
+        let started = entry.merge_started_at.unwrap();
+        let resolved = entry.resolved_at.unwrap();
+        assert!(entry.queued_at <= started && started <= resolved);
     }
 
     #[test]
@@ -947,12 +967,22 @@ mod tests {
         queue.enqueue("loop-retry", "test").unwrap();
         queue.mark_merging("loop-retry", 100).unwrap();
         queue.mark_needs_review("loop-retry", "conflicts").unwrap();
+        let reviewed_at = queue
+            .get_entry("loop-retry")
+            .unwrap()
+            .unwrap()
+            .resolved_at
+            .unwrap();
 
         // Can retry (mark_merging) from needs_review
         queue.mark_merging("loop-retry", 200).unwrap();
         let entry = queue.get_entry("loop-retry").unwrap().unwrap();
         assert_eq!(entry.state, MergeState::Merging);
         assert_eq!(entry.merge_pid, Some(200));
+
+        // The retry starts a new attempt that hasn't resolved yet
+        assert!(entry.merge_started_at.unwrap() >= reviewed_at);
+        assert!(entry.resolved_at.is_none());
     }
 
     #[test]
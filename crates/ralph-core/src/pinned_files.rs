@@ -0,0 +1,222 @@
+//! Pinned context files.
+//!
+//! `.ralph/agent/pinned-files` lists repository paths, one per line, that are
+//! inlined into every iteration's prompt. The manifest is re-read each
+//! iteration, so it can be edited while a loop is running to steer the
+//! agent's attention without restating it in guidance. Blank lines and lines
+//! starting with `#` are ignored.
+
+use crate::text::floor_char_boundary;
+use std::fmt::Write as _;
+use std::path::{Component, Path, PathBuf};
+
+/// Default character budget for all pinned file content (~8000 tokens).
+pub const DEFAULT_PINNED_CHAR_BUDGET: usize = 8000 * 4;
+
+/// Reads the pinned-files manifest.
+///
+/// Returns an empty list if the manifest doesn't exist or can't be read.
+pub fn read_pinned_manifest(path: &Path) -> Vec<String> {
+    let Ok(content) = std::fs::read_to_string(path) else {
+        return Vec::new();
+    };
+    content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(ToString::to_string)
+        .collect()
+}
+
+/// Renders pinned files as a `<pinned-files>` prompt block.
+///
+/// Paths are resolved against `workspace_root`; absolute paths, `..`
+/// components and symlinks leading outside the workspace are rejected so a
+/// manifest entry can't pull arbitrary files into the prompt. Files are
+/// included in manifest order until `char_budget` is spent; a file that
+/// overflows the budget is truncated, and any after it are listed by path
+/// only. Returns `None` if `paths` is empty.
+pub fn render_pinned_files(
+    workspace_root: &Path,
+    paths: &[String],
+    char_budget: usize,
+) -> Option<String> {
+    if paths.is_empty() {
+        return None;
+    }
+
+    let mut remaining = char_budget;
+    let mut section = String::from("<pinned-files>\n");
+    for path in paths {
+        let content = match resolve_pinned_path(workspace_root, path)
+            .and_then(|resolved| std::fs::read_to_string(resolved).map_err(|e| e.to_string()))
+        {
+            Ok(content) => content,
+            Err(e) => {
+                let _ = writeln!(
+                    section,
+                    "<file path=\"{}\" error=\"{}\" />",
+                    escape_attr(path),
+                    escape_attr(&e)
+                );
+                continue;
+            }
+        };
+        let path = escape_attr(path);
+
+        if remaining == 0 {
+            let _ = writeln!(
+                section,
+                "<file path=\"{path}\" omitted=\"budget exhausted; read it directly\" />"
+            );
+            continue;
+        }
+
+        let len = content.len();
+        let body = if len > remaining {
+            let end = floor_char_boundary(&content, remaining);
+            format!(
+                "{}\n<!-- truncated ({} chars omitted) -->",
+                &content[..end],
+                len - end
+            )
+        } else {
+            content
+        };
+        remaining = remaining.saturating_sub(len);
+
+        let _ = writeln!(
+            section,
+            "<file path=\"{path}\">\n{}\n</file>",
+            body.trim_end()
+        );
+    }
+    section.push_str("</pinned-files>\n\n");
+    Some(section)
+}
+
+/// Resolves a manifest entry to a file inside `workspace_root`.
+fn resolve_pinned_path(workspace_root: &Path, path: &str) -> Result<PathBuf, String> {
+    let relative = Path::new(path);
+    if relative
+        .components()
+        .any(|c| !matches!(c, Component::Normal(_) | Component::CurDir))
+    {
+        return Err("path must be relative and inside the workspace".to_string());
+    }
+
+    let resolved = workspace_root
+        .join(relative)
+        .canonicalize()
+        .map_err(|e| e.to_string())?;
+    let root = workspace_root.canonicalize().map_err(|e| e.to_string())?;
+    if !resolved.starts_with(&root) {
+        return Err("path resolves outside the workspace".to_string());
+    }
+    Ok(resolved)
+}
+
+/// Escapes a value for use inside a double-quoted attribute.
+fn escape_attr(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('"', "&quot;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_read_manifest_skips_comments_and_blanks() {
+        let tmp = TempDir::new().unwrap();
+        let manifest = tmp.path().join("pinned-files");
+        std::fs::write(
+            &manifest,
+            "# auth work\nsrc/auth.rs\n\n  src/session.rs  \n",
+        )
+        .unwrap();
+
+        assert_eq!(
+            read_pinned_manifest(&manifest),
+            vec!["src/auth.rs".to_string(), "src/session.rs".to_string()]
+        );
+        assert!(read_pinned_manifest(&tmp.path().join("missing")).is_empty());
+    }
+
+    #[test]
+    fn test_render_includes_content_and_missing_files() {
+        let tmp = TempDir::new().unwrap();
+        std::fs::write(tmp.path().join("a.rs"), "fn a() {}\n").unwrap();
+
+        let section = render_pinned_files(
+            tmp.path(),
+            &["a.rs".to_string(), "gone.rs".to_string()],
+            DEFAULT_PINNED_CHAR_BUDGET,
+        )
+        .unwrap();
+
+        assert!(section.contains("<file path=\"a.rs\">\nfn a() {}\n</file>"));
+        assert!(section.contains("<file path=\"gone.rs\" error="));
+        assert!(render_pinned_files(tmp.path(), &[], DEFAULT_PINNED_CHAR_BUDGET).is_none());
+    }
+
+    #[test]
+    fn test_render_rejects_paths_outside_workspace() {
+        let outer = TempDir::new().unwrap();
+        let root = outer.path().join("repo");
+        std::fs::create_dir_all(&root).unwrap();
+        std::fs::write(outer.path().join("secret.txt"), "hunter2").unwrap();
+        #[cfg(unix)]
+        std::os::unix::fs::symlink(outer.path().join("secret.txt"), root.join("link.txt")).unwrap();
+
+        let absolute = outer.path().join("secret.txt").display().to_string();
+        let section = render_pinned_files(
+            &root,
+            &[
+                "../secret.txt".to_string(),
+                absolute,
+                "link.txt".to_string(),
+            ],
+            DEFAULT_PINNED_CHAR_BUDGET,
+        )
+        .unwrap();
+
+        assert!(!section.contains("hunter2"));
+        assert!(section.contains("<file path=\"../secret.txt\" error=\"path must be relative"));
+    }
+
+    #[test]
+    fn test_render_escapes_attributes() {
+        let tmp = TempDir::new().unwrap();
+
+        let section = render_pinned_files(
+            tmp.path(),
+            &["we\"ird<.rs".to_string()],
+            DEFAULT_PINNED_CHAR_BUDGET,
+        )
+        .unwrap();
+
+        assert!(section.contains("<file path=\"we&quot;ird&lt;.rs\" error=\""));
+    }
+
+    #[test]
+    fn test_render_respects_budget() {
+        let tmp = TempDir::new().unwrap();
+        std::fs::write(tmp.path().join("big.txt"), "x".repeat(100)).unwrap();
+        std::fs::write(tmp.path().join("next.txt"), "next").unwrap();
+
+        let section = render_pinned_files(
+            tmp.path(),
+            &["big.txt".to_string(), "next.txt".to_string()],
+            40,
+        )
+        .unwrap();
+
+        assert!(section.contains("truncated (60 chars omitted)"));
+        assert!(section.contains("<file path=\"next.txt\" omitted="));
+    }
+}
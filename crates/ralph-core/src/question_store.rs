@@ -0,0 +1,607 @@
+//! Persistent store for `human.interact` questions.
+//!
+//! Questions asked through the robot service are recorded in
+//! `.ralph/agent/questions.jsonl` so they survive restarts and leave an
+//! audit trail: each question is `pending` until a `human.response` answers
+//! it or its `timeout_at` passes, at which point it becomes `expired`.
+//!
+//! Like [`TaskStore`](crate::TaskStore), writes go through an exclusive file
+//! lock so loops in multiple worktrees can share one file. Worktree loops
+//! reach the main repo's file through a symlink, which is resolved on load so
+//! every loop locks the same file.
+
+use crate::file_lock::FileLock;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet};
+use std::io;
+use std::path::{Path, PathBuf};
+use tracing::warn;
+
+/// Minimum word-overlap score for a past question to count as similar.
+pub const SUGGESTION_MIN_SIMILARITY: f64 = 0.5;
+
+/// Lowercase words of three or more characters, used for similarity matching.
+fn similarity_words(text: &str) -> HashSet<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| word.chars().count() >= 3)
+        .map(str::to_lowercase)
+        .collect()
+}
+
+/// Lifecycle state of a question.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum QuestionStatus {
+    /// Waiting for a human response
+    Pending,
+    /// A human responded
+    Answered,
+    /// The timeout passed without a response
+    Expired,
+}
+
+/// Aggregate statistics over recorded questions.
+///
+/// Used to judge how often a loop needs a human and how quickly one shows up,
+/// which helps tune how much autonomy a preset is given.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct QuestionStats {
+    /// Questions recorded
+    pub total: usize,
+    /// Questions still awaiting a response
+    pub pending: usize,
+    /// Questions a human answered
+    pub answered: usize,
+    /// Questions that timed out
+    pub expired: usize,
+    /// Share of resolved questions that timed out (0.0 when none resolved)
+    pub timeout_rate: f64,
+    /// Median seconds from asking to answering, over answered questions
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub median_response_secs: Option<i64>,
+    /// Questions asked per UTC day (`YYYY-MM-DD`)
+    pub per_day: BTreeMap<String, usize>,
+    /// Questions asked per loop (`primary` for questions without a loop ID)
+    pub per_loop: BTreeMap<String, usize>,
+    /// Questions asked per hat (`unknown` for questions without a hat)
+    pub per_hat: BTreeMap<String, usize>,
+    /// Questions asked per builtin preset (`custom` for other configs)
+    pub per_preset: BTreeMap<String, usize>,
+}
+
+/// A question sent to the human via `human.interact`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Question {
+    /// Unique ID: q-{unix_timestamp}-{4_hex_chars}
+    pub id: String,
+
+    /// The question text (the `human.interact` payload)
+    pub text: String,
+
+    /// Loop that asked the question, if known
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub loop_id: Option<String>,
+
+    /// Hat that was active when the question was asked, if known
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hat: Option<String>,
+
+    /// Builtin preset the loop was running, if any
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub preset: Option<String>,
+
+    /// Current state
+    pub status: QuestionStatus,
+
+    /// When the question was asked
+    pub asked_at: DateTime<Utc>,
+
+    /// When the question stops waiting for a response
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timeout_at: Option<DateTime<Utc>>,
+
+    /// The human's response, if answered
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response: Option<String>,
+
+    /// When the question was answered or expired
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resolved_at: Option<DateTime<Utc>>,
+}
+
+impl Question {
+    /// Creates a pending question asked at `now` that times out after `timeout_secs`.
+    pub fn new(text: impl Into<String>, now: DateTime<Utc>, timeout_secs: u64) -> Self {
+        let timeout = chrono::Duration::seconds(i64::try_from(timeout_secs).unwrap_or(i64::MAX));
+        Self {
+            id: Self::generate_id(),
+            text: text.into(),
+            loop_id: None,
+            hat: None,
+            preset: None,
+            status: QuestionStatus::Pending,
+            asked_at: now,
+            timeout_at: now.checked_add_signed(timeout),
+            response: None,
+            resolved_at: None,
+        }
+    }
+
+    /// Sets the loop ID for this question.
+    pub fn with_loop_id(mut self, loop_id: Option<String>) -> Self {
+        self.loop_id = loop_id;
+        self
+    }
+
+    /// Sets the hat that asked this question.
+    pub fn with_hat(mut self, hat: Option<String>) -> Self {
+        self.hat = hat;
+        self
+    }
+
+    /// Sets the builtin preset the asking loop was running.
+    pub fn with_preset(mut self, preset: Option<String>) -> Self {
+        self.preset = preset;
+        self
+    }
+
+    /// Generates a unique question ID: q-{timestamp}-{hex_suffix}
+    ///
+    /// IDs are unique within a process even when generated back to back.
+    pub fn generate_id() -> String {
+        crate::utils::timestamped_id("q")
+    }
+
+    /// Returns true if the question is pending and its timeout has passed.
+    pub fn is_overdue(&self, now: DateTime<Utc>) -> bool {
+        self.status == QuestionStatus::Pending && self.timeout_at.is_some_and(|t| t <= now)
+    }
+}
+
+/// A store for `human.interact` questions with JSONL persistence and file locking.
+pub struct QuestionStore {
+    path: PathBuf,
+    questions: Vec<Question>,
+    lock: FileLock,
+}
+
+fn read_questions(path: &Path) -> io::Result<Vec<Question>> {
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let content = std::fs::read_to_string(path)?;
+    Ok(content
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .filter_map(|line| match serde_json::from_str(line) {
+            Ok(question) => Some(question),
+            Err(e) => {
+                warn!(error = %e, "Skipping malformed question line in JSONL");
+                None
+            }
+        })
+        .collect())
+}
+
+impl QuestionStore {
+    /// Loads questions from the JSONL file at the given path.
+    ///
+    /// If the file doesn't exist, returns an empty store. A symlinked path is
+    /// resolved first so the lock file sits next to the real file.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        let path = match std::fs::read_link(path) {
+            Ok(target) => path.parent().unwrap_or(Path::new("")).join(target),
+            Err(_) => path.to_path_buf(),
+        };
+        let lock = FileLock::new(&path)?;
+        let questions = {
+            let _guard = lock.shared()?;
+            read_questions(&path)?
+        };
+        Ok(Self {
+            path,
+            questions,
+            lock,
+        })
+    }
+
+    /// Executes a read-modify-write operation atomically.
+    ///
+    /// Acquires an exclusive lock, reloads from disk, runs `f`, and saves.
+    pub fn with_exclusive_lock<F, T>(&mut self, f: F) -> io::Result<T>
+    where
+        F: FnOnce(&mut Self) -> T,
+    {
+        let _guard = self.lock.exclusive()?;
+        self.questions = read_questions(&self.path)?;
+
+        let result = f(self);
+
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let mut content = String::new();
+        for question in &self.questions {
+            let line = serde_json::to_string(question)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+            content.push_str(&line);
+            content.push('\n');
+        }
+        std::fs::write(&self.path, content)?;
+
+        Ok(result)
+    }
+
+    /// Adds a question to the store.
+    pub fn add(&mut self, question: Question) -> &Question {
+        self.questions.push(question);
+        self.questions.last().unwrap()
+    }
+
+    /// Gets a question by ID.
+    pub fn get(&self, id: &str) -> Option<&Question> {
+        self.questions.iter().find(|q| q.id == id)
+    }
+
+    /// Records a response to a pending question.
+    ///
+    /// Returns `None` if the question doesn't exist or is no longer pending.
+    pub fn answer(&mut self, id: &str, response: &str, now: DateTime<Utc>) -> Option<&Question> {
+        let question = self
+            .questions
+            .iter_mut()
+            .find(|q| q.id == id && q.status == QuestionStatus::Pending)?;
+        question.status = QuestionStatus::Answered;
+        question.response = Some(response.to_string());
+        question.resolved_at = Some(now);
+        Some(question)
+    }
+
+    /// Marks a pending question as expired.
+    pub fn expire(&mut self, id: &str, now: DateTime<Utc>) -> Option<&Question> {
+        let question = self
+            .questions
+            .iter_mut()
+            .find(|q| q.id == id && q.status == QuestionStatus::Pending)?;
+        question.status = QuestionStatus::Expired;
+        question.resolved_at = Some(now);
+        Some(question)
+    }
+
+    /// Expires every pending question whose timeout has passed.
+    ///
+    /// Returns the newly expired questions so callers can emit timeout events.
+    pub fn expire_overdue(&mut self, now: DateTime<Utc>) -> Vec<Question> {
+        let mut expired = Vec::new();
+        for question in &mut self.questions {
+            if question.is_overdue(now) {
+                question.status = QuestionStatus::Expired;
+                question.resolved_at = Some(now);
+                expired.push(question.clone());
+            }
+        }
+        expired
+    }
+
+    /// Returns all questions as a slice.
+    pub fn all(&self) -> &[Question] {
+        &self.questions
+    }
+
+    /// Returns questions still awaiting a response.
+    pub fn pending(&self) -> Vec<&Question> {
+        self.questions
+            .iter()
+            .filter(|q| q.status == QuestionStatus::Pending)
+            .collect()
+    }
+
+    /// Returns answered and expired questions, most recently resolved first.
+    pub fn history(&self) -> Vec<&Question> {
+        let mut resolved: Vec<_> = self
+            .questions
+            .iter()
+            .filter(|q| q.status != QuestionStatus::Pending)
+            .collect();
+        resolved.sort_by_key(|q| std::cmp::Reverse(q.resolved_at));
+        resolved
+    }
+
+    /// Returns responses to previously answered questions similar to `text`.
+    ///
+    /// Similarity is word overlap (Jaccard index over lowercase words of three
+    /// or more characters); only questions scoring at least
+    /// [`SUGGESTION_MIN_SIMILARITY`] are considered. Responses are ordered
+    /// best match first, deduplicated, and capped at `limit`.
+    pub fn suggest_responses(&self, text: &str, limit: usize) -> Vec<String> {
+        let words = similarity_words(text);
+        if words.is_empty() || limit == 0 {
+            return Vec::new();
+        }
+
+        let mut scored: Vec<(f64, &Question)> = self
+            .questions
+            .iter()
+            .filter(|q| q.status == QuestionStatus::Answered && q.response.is_some())
+            .filter_map(|q| {
+                let other = similarity_words(&q.text);
+                let union = words.union(&other).count();
+                if union == 0 {
+                    return None;
+                }
+                #[allow(clippy::cast_precision_loss)]
+                let score = words.intersection(&other).count() as f64 / union as f64;
+                (score >= SUGGESTION_MIN_SIMILARITY).then_some((score, q))
+            })
+            .collect();
+        // Best match first; more recent answers win ties
+        scored.sort_by(|(a, qa), (b, qb)| {
+            b.total_cmp(a)
+                .then_with(|| qb.resolved_at.cmp(&qa.resolved_at))
+        });
+
+        let mut suggestions: Vec<String> = Vec::new();
+        for (_, question) in scored {
+            if suggestions.len() >= limit {
+                break;
+            }
+            let Some(response) = &question.response else {
+                continue;
+            };
+            if !suggestions.contains(response) {
+                suggestions.push(response.clone());
+            }
+        }
+        suggestions
+    }
+
+    /// Computes aggregate statistics over all recorded questions.
+    pub fn stats(&self) -> QuestionStats {
+        let mut stats = QuestionStats {
+            total: self.questions.len(),
+            ..QuestionStats::default()
+        };
+        let mut latencies = Vec::new();
+
+        for question in &self.questions {
+            match question.status {
+                QuestionStatus::Pending => stats.pending += 1,
+                QuestionStatus::Answered => {
+                    stats.answered += 1;
+                    if let Some(resolved_at) = question.resolved_at {
+                        latencies.push((resolved_at - question.asked_at).num_seconds());
+                    }
+                }
+                QuestionStatus::Expired => stats.expired += 1,
+            }
+            *stats
+                .per_day
+                .entry(question.asked_at.format("%Y-%m-%d").to_string())
+                .or_default() += 1;
+            *stats
+                .per_loop
+                .entry(
+                    question
+                        .loop_id
+                        .clone()
+                        .unwrap_or_else(|| "primary".to_string()),
+                )
+                .or_default() += 1;
+            *stats
+                .per_hat
+                .entry(
+                    question
+                        .hat
+                        .clone()
+                        .unwrap_or_else(|| "unknown".to_string()),
+                )
+                .or_default() += 1;
+            *stats
+                .per_preset
+                .entry(
+                    question
+                        .preset
+                        .clone()
+                        .unwrap_or_else(|| "custom".to_string()),
+                )
+                .or_default() += 1;
+        }
+
+        let resolved = stats.answered + stats.expired;
+        if resolved > 0 {
+            #[allow(clippy::cast_precision_loss)]
+            let rate = stats.expired as f64 / resolved as f64;
+            stats.timeout_rate = rate;
+        }
+
+        latencies.sort_unstable();
+        stats.median_response_secs = match latencies.len() {
+            0 => None,
+            n if n % 2 == 1 => Some(latencies[n / 2]),
+            n => Some(i64::midpoint(latencies[n / 2 - 1], latencies[n / 2])),
+        };
+
+        stats
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn ts(secs: i64) -> DateTime<Utc> {
+        DateTime::from_timestamp(1_700_000_000 + secs, 0).unwrap()
+    }
+
+    #[test]
+    fn test_load_nonexistent_file() {
+        let tmp = TempDir::new().unwrap();
+        let store = QuestionStore::load(&tmp.path().join("questions.jsonl")).unwrap();
+        assert!(store.all().is_empty());
+    }
+
+    #[test]
+    fn test_answer_persists_response() {
+        let tmp = TempDir::new().unwrap();
+        let path = tmp.path().join("questions.jsonl");
+
+        let mut store = QuestionStore::load(&path).unwrap();
+        let id = store
+            .with_exclusive_lock(|s| s.add(Question::new("Which DB?", ts(0), 300)).id.clone())
+            .unwrap();
+        store
+            .with_exclusive_lock(|s| {
+                s.answer(&id, "Postgres", ts(10)).unwrap();
+            })
+            .unwrap();
+
+        let loaded = QuestionStore::load(&path).unwrap();
+        let question = loaded.get(&id).unwrap();
+        assert_eq!(question.status, QuestionStatus::Answered);
+        assert_eq!(question.response.as_deref(), Some("Postgres"));
+        assert_eq!(question.resolved_at, Some(ts(10)));
+        assert!(loaded.pending().is_empty());
+    }
+
+    #[test]
+    fn test_expire_overdue_only_touches_pending_past_timeout() {
+        let tmp = TempDir::new().unwrap();
+        let mut store = QuestionStore::load(&tmp.path().join("questions.jsonl")).unwrap();
+
+        let overdue = store.add(Question::new("Old?", ts(0), 60)).id.clone();
+        let fresh = store.add(Question::new("New?", ts(50), 60)).id.clone();
+
+        let expired = store.expire_overdue(ts(100));
+        assert_eq!(expired.len(), 1);
+        assert_eq!(expired[0].id, overdue);
+        assert_eq!(store.get(&fresh).unwrap().status, QuestionStatus::Pending);
+
+        // Already-expired questions can't be answered
+        assert!(store.answer(&overdue, "late", ts(120)).is_none());
+    }
+
+    #[test]
+    fn test_history_orders_by_resolution() {
+        let tmp = TempDir::new().unwrap();
+        let mut store = QuestionStore::load(&tmp.path().join("questions.jsonl")).unwrap();
+
+        let first = store.add(Question::new("First?", ts(0), 60)).id.clone();
+        let second = store.add(Question::new("Second?", ts(0), 60)).id.clone();
+        store.add(Question::new("Third?", ts(0), 60));
+
+        store.answer(&first, "yes", ts(5));
+        store.expire(&second, ts(60));
+
+        let history: Vec<_> = store.history().iter().map(|q| q.id.clone()).collect();
+        assert_eq!(history, vec![second, first]);
+    }
+
+    #[test]
+    fn test_stats_counts_latency_and_timeouts() {
+        let tmp = TempDir::new().unwrap();
+        let mut store = QuestionStore::load(&tmp.path().join("questions.jsonl")).unwrap();
+
+        let mut ids = Vec::new();
+        for (i, (loop_id, hat)) in [
+            (None, Some("planner")),
+            (None, Some("builder")),
+            (Some("loop-a"), Some("builder")),
+            (None, None),
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            let question = Question::new(format!("Q{i}?"), ts(0), 600)
+                .with_loop_id(loop_id.map(String::from))
+                .with_hat(hat.map(String::from))
+                .with_preset(Some("feature".to_string()));
+            ids.push(store.add(question).id.clone());
+        }
+        store.answer(&ids[0], "yes", ts(10));
+        store.answer(&ids[1], "no", ts(30));
+        store.expire(&ids[2], ts(600));
+
+        let stats = store.stats();
+
+        assert_eq!(stats.total, 4);
+        assert_eq!(stats.pending, 1);
+        assert_eq!(stats.answered, 2);
+        assert_eq!(stats.expired, 1);
+        assert!((stats.timeout_rate - 1.0 / 3.0).abs() < f64::EPSILON);
+        assert_eq!(stats.median_response_secs, Some(20));
+        assert_eq!(stats.per_day.values().sum::<usize>(), 4);
+        assert_eq!(stats.per_loop["primary"], 3);
+        assert_eq!(stats.per_loop["loop-a"], 1);
+        assert_eq!(stats.per_hat["builder"], 2);
+        assert_eq!(stats.per_hat["planner"], 1);
+        assert_eq!(stats.per_hat["unknown"], 1);
+        assert_eq!(stats.per_preset["feature"], 4);
+
+        let empty = QuestionStore::load(&tmp.path().join("none.jsonl")).unwrap();
+        assert_eq!(empty.stats(), QuestionStats::default());
+    }
+
+    #[test]
+    fn test_suggest_responses_matches_similar_answered_questions() {
+        let tmp = TempDir::new().unwrap();
+        let mut store = QuestionStore::load(&tmp.path().join("questions.jsonl")).unwrap();
+
+        let asked = [
+            (
+                "q-1",
+                "Should I deploy to production now?",
+                Some("No, staging first"),
+            ),
+            ("q-2", "Deploy to production now?", Some("Yes")),
+            (
+                "q-3",
+                "Should I deploy to production?",
+                Some("No, staging first"),
+            ),
+            ("q-4", "Which database should I use?", Some("Postgres")),
+            ("q-5", "Should I deploy to production now?", None),
+        ];
+        for (i, (id, text, response)) in asked.into_iter().enumerate() {
+            let mut question = Question::new(text, ts(0), 600);
+            question.id = id.to_string();
+            store.add(question);
+            if let Some(response) = response {
+                store.answer(id, response, ts(10 + i64::try_from(i).unwrap()));
+            }
+        }
+
+        let suggestions = store.suggest_responses("Should we deploy to production now?", 3);
+        assert_eq!(suggestions, vec!["No, staging first", "Yes"]);
+
+        assert_eq!(store.suggest_responses("deploy production now", 1).len(), 1);
+        assert!(
+            store
+                .suggest_responses("What colour is the logo?", 3)
+                .is_empty()
+        );
+        assert!(store.suggest_responses("?", 3).is_empty());
+    }
+
+    #[test]
+    fn test_suggest_responses_with_zero_limit() {
+        let tmp = TempDir::new().unwrap();
+        let mut store = QuestionStore::load(&tmp.path().join("questions.jsonl")).unwrap();
+        let id = store
+            .add(Question::new("Should I deploy to production?", ts(0), 600))
+            .id
+            .clone();
+        store.answer(&id, "No, staging first", ts(10));
+
+        assert_eq!(
+            store.suggest_responses("Should I deploy to production?", 1),
+            vec!["No, staging first"]
+        );
+        assert!(
+            store
+                .suggest_responses("Should I deploy to production?", 0)
+                .is_empty()
+        );
+    }
+}
@@ -0,0 +1,114 @@
+//! Typed steering commands carried in `human.guidance` events.
+//!
+//! Free-text guidance is still the default, but common interventions can be
+//! sent as a JSON payload with a `command` tag:
+//!
+//! ```text
+//! ralph emit human.guidance '{"command": "focus_on", "path": "src/auth"}' --json
+//! ```
+//!
+//! The event loop renders each command as an unambiguous directive in the
+//! `## ROBOT GUIDANCE` prompt section. `change_priority` is also applied to
+//! the task store directly, since it doesn't need the agent's cooperation.
+
+use serde::{Deserialize, Serialize};
+
+/// A structured steering verb.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "command", rename_all = "snake_case")]
+pub enum SteeringCommand {
+    /// Stop working on the current task and move to the next ready one
+    SkipCurrentTask,
+    /// Concentrate on a specific path in the repository
+    FocusOn { path: String },
+    /// Wrap up the current iteration immediately
+    AbortIteration,
+    /// Change a task's priority (1 = highest)
+    ChangePriority { task_id: String, priority: u8 },
+}
+
+impl SteeringCommand {
+    /// Parses a guidance payload as a steering command.
+    ///
+    /// Returns `None` for free-text guidance or JSON without a known `command`.
+    pub fn parse(payload: &str) -> Option<Self> {
+        let trimmed = payload.trim();
+        if !trimmed.starts_with('{') {
+            return None;
+        }
+        serde_json::from_str(trimmed).ok()
+    }
+
+    /// Renders the command as a directive for the agent prompt.
+    pub fn to_guidance(&self) -> String {
+        match self {
+            SteeringCommand::SkipCurrentTask => {
+                "Skip the task you are currently working on. Leave it open, note why in the \
+                 scratchpad, and pick the next ready task."
+                    .to_string()
+            }
+            SteeringCommand::FocusOn { path } => format!(
+                "Focus this work on `{path}`. Avoid changes outside it unless strictly required."
+            ),
+            SteeringCommand::AbortIteration => {
+                "Stop the current line of work now. Record progress in the scratchpad and emit \
+                 your event without starting anything new."
+                    .to_string()
+            }
+            SteeringCommand::ChangePriority { task_id, priority } => format!(
+                "Task {task_id} has been reprioritized to P{}. Re-check `ralph tools task ready` \
+                 before choosing what to work on.",
+                (*priority).clamp(1, 5)
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_ignores_free_text() {
+        assert_eq!(SteeringCommand::parse("Please write tests first"), None);
+        assert_eq!(SteeringCommand::parse(r#"{"command": "dance"}"#), None);
+    }
+
+    #[test]
+    fn test_parse_commands() {
+        assert_eq!(
+            SteeringCommand::parse(r#"{"command": "skip_current_task"}"#),
+            Some(SteeringCommand::SkipCurrentTask)
+        );
+        assert_eq!(
+            SteeringCommand::parse(r#" {"command": "focus_on", "path": "src/auth"} "#),
+            Some(SteeringCommand::FocusOn {
+                path: "src/auth".to_string()
+            })
+        );
+        assert_eq!(
+            SteeringCommand::parse(
+                r#"{"command": "change_priority", "task_id": "task-1-abcd", "priority": 1}"#
+            ),
+            Some(SteeringCommand::ChangePriority {
+                task_id: "task-1-abcd".to_string(),
+                priority: 1
+            })
+        );
+    }
+
+    #[test]
+    fn test_to_guidance_mentions_target() {
+        let focus = SteeringCommand::FocusOn {
+            path: "crates/ralph-core".to_string(),
+        };
+        assert!(focus.to_guidance().contains("`crates/ralph-core`"));
+
+        let priority = SteeringCommand::ChangePriority {
+            task_id: "task-1-abcd".to_string(),
+            priority: 9,
+        };
+        assert!(priority.to_guidance().contains("task-1-abcd"));
+        assert!(priority.to_guidance().contains("P5"));
+    }
+}
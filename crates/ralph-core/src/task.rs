@@ -87,14 +87,10 @@ impl Task {
     }
 
     /// Generates a unique task ID: task-{timestamp}-{hex_suffix}
+    ///
+    /// IDs are unique within a process even when generated back to back.
     pub fn generate_id() -> String {
-        use std::time::{SystemTime, UNIX_EPOCH};
-        let duration = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .expect("Time went backwards");
-        let timestamp = duration.as_secs();
-        let hex_suffix = format!("{:04x}", duration.subsec_micros() % 0x10000);
-        format!("task-{}-{}", timestamp, hex_suffix)
+        crate::utils::timestamped_id("task")
     }
 
     /// Returns true if this task is ready to work on (open + no blockers pending).
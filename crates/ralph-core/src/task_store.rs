@@ -16,10 +16,68 @@
 
 use crate::file_lock::FileLock;
 use crate::task::{Task, TaskStatus};
+use serde::{Deserialize, Serialize};
 use std::io;
 use std::path::Path;
 use tracing::warn;
 
+/// A single operation in a bulk task update.
+///
+/// Serialized with an `op` tag, e.g. `{"op": "reprioritize", "id": "task-1-abcd", "priority": 1}`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum TaskOperation {
+    /// Mark the task as complete
+    Close { id: String },
+    /// Mark the task as failed
+    Fail { id: String },
+    /// Change the task priority (must be 1-5)
+    Reprioritize { id: String, priority: u8 },
+    /// Replace the task's blockers
+    Reblock { id: String, blocked_by: Vec<String> },
+    /// Remove the task from the store
+    Delete { id: String },
+}
+
+impl TaskOperation {
+    /// Returns the ID of the task this operation targets.
+    pub fn task_id(&self) -> &str {
+        match self {
+            TaskOperation::Close { id }
+            | TaskOperation::Fail { id }
+            | TaskOperation::Reprioritize { id, .. }
+            | TaskOperation::Reblock { id, .. }
+            | TaskOperation::Delete { id } => id,
+        }
+    }
+
+    /// Checks the operation's arguments without looking at any tasks.
+    ///
+    /// # Errors
+    ///
+    /// Returns a description of the problem, e.g. an out-of-range priority.
+    pub fn validate(&self) -> Result<(), String> {
+        match self {
+            TaskOperation::Reprioritize { priority, .. } if !(1..=5).contains(priority) => {
+                Err(format!("priority must be between 1 and 5, got {priority}"))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Per-item outcome of a bulk task update.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TaskOperationResult {
+    /// ID of the targeted task
+    pub id: String,
+    /// Whether the operation was applied
+    pub ok: bool,
+    /// Reason the operation was rejected, if it failed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
 /// A store for managing tasks with JSONL persistence and file locking.
 pub struct TaskStore {
     path: std::path::PathBuf,
@@ -225,6 +283,103 @@ impl TaskStore {
         None
     }
 
+    /// Removes a task by ID and returns it.
+    ///
+    /// Blocker references to the removed task are dropped from the remaining
+    /// tasks so they don't stay blocked on a task that no longer exists.
+    pub fn remove(&mut self, id: &str) -> Option<Task> {
+        let index = self.tasks.iter().position(|t| t.id == id)?;
+        let removed = self.tasks.remove(index);
+        for task in &mut self.tasks {
+            task.blocked_by.retain(|blocker| blocker != id);
+        }
+        Some(removed)
+    }
+
+    /// Applies a single operation to the in-memory tasks.
+    ///
+    /// Does not persist; use `apply_bulk()` to apply and save atomically.
+    pub fn apply(&mut self, op: &TaskOperation) -> TaskOperationResult {
+        let id = op.task_id().to_string();
+        let outcome = match op {
+            TaskOperation::Close { id } => self.close(id).map(|_| ()).ok_or("task not found"),
+            TaskOperation::Fail { id } => self.fail(id).map(|_| ()).ok_or("task not found"),
+            TaskOperation::Reprioritize { id, priority } => {
+                if (1..=5).contains(priority) {
+                    self.get_mut(id)
+                        .map(|task| task.priority = *priority)
+                        .ok_or("task not found")
+                } else {
+                    Err("priority must be between 1 and 5")
+                }
+            }
+            TaskOperation::Reblock { id, blocked_by } => {
+                if blocked_by.iter().any(|blocker| blocker == id) {
+                    Err("task cannot block itself")
+                } else if blocked_by.iter().any(|blocker| self.get(blocker).is_none()) {
+                    Err("blocker not found")
+                } else if blocked_by
+                    .iter()
+                    .any(|blocker| self.is_blocked_by(blocker, id))
+                {
+                    Err("blockers would form a cycle")
+                } else {
+                    self.get_mut(id)
+                        .map(|task| task.blocked_by.clone_from(blocked_by))
+                        .ok_or("task not found")
+                }
+            }
+            TaskOperation::Delete { id } => self.remove(id).map(|_| ()).ok_or("task not found"),
+        };
+
+        TaskOperationResult {
+            id,
+            ok: outcome.is_ok(),
+            error: outcome.err().map(str::to_string),
+        }
+    }
+
+    /// Returns true if `task_id` is blocked by `blocker_id`, directly or through
+    /// a chain of blockers.
+    fn is_blocked_by(&self, task_id: &str, blocker_id: &str) -> bool {
+        let mut stack = vec![task_id];
+        let mut seen = std::collections::HashSet::new();
+        while let Some(current) = stack.pop() {
+            if !seen.insert(current) {
+                continue;
+            }
+            let Some(task) = self.get(current) else {
+                continue;
+            };
+            for blocker in &task.blocked_by {
+                if blocker == blocker_id {
+                    return true;
+                }
+                stack.push(blocker);
+            }
+        }
+        false
+    }
+
+    /// Applies a batch of operations inside a single exclusive-lock transaction.
+    ///
+    /// Every operation is validated first; if any has invalid arguments (such
+    /// as an out-of-range priority), nothing is applied and an
+    /// `InvalidInput` error is returned. Otherwise operations run in order, a
+    /// failed item doesn't abort the rest, and one result is returned per
+    /// operation.
+    pub fn apply_bulk(&mut self, ops: &[TaskOperation]) -> io::Result<Vec<TaskOperationResult>> {
+        for (index, op) in ops.iter().enumerate() {
+            op.validate().map_err(|e| {
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("operation {} ({}): {e}", index + 1, op.task_id()),
+                )
+            })?;
+        }
+        self.with_exclusive_lock(|store| ops.iter().map(|op| store.apply(op)).collect())
+    }
+
     /// Returns all tasks as a slice.
     pub fn all(&self) -> &[Task] {
         &self.tasks
@@ -489,6 +644,148 @@ mod tests {
         assert_eq!(final_store.all().len(), 2);
     }
 
+    #[test]
+    fn test_remove_clears_blocker_references() {
+        let tmp = TempDir::new().unwrap();
+        let path = tmp.path().join("tasks.jsonl");
+        let mut store = TaskStore::load(&path).unwrap();
+
+        let blocker = Task::new("Blocker".to_string(), 1);
+        let blocker_id = blocker.id.clone();
+        store.add(blocker);
+        let blocked = Task::new("Blocked".to_string(), 1).with_blocker(blocker_id.clone());
+        let blocked_id = blocked.id.clone();
+        store.add(blocked);
+
+        let removed = store.remove(&blocker_id).unwrap();
+        assert_eq!(removed.title, "Blocker");
+        assert_eq!(store.all().len(), 1);
+        assert!(store.get(&blocked_id).unwrap().blocked_by.is_empty());
+        assert!(store.remove(&blocker_id).is_none());
+    }
+
+    #[test]
+    fn test_apply_bulk_reports_per_item_results() {
+        let tmp = TempDir::new().unwrap();
+        let path = tmp.path().join("tasks.jsonl");
+
+        let mut store = TaskStore::load(&path).unwrap();
+        let first = Task::new("First".to_string(), 3);
+        let first_id = first.id.clone();
+        let second = Task::new("Second".to_string(), 3);
+        let second_id = second.id.clone();
+        store.add(first);
+        store.add(second);
+        store.save().unwrap();
+
+        let ops = vec![
+            TaskOperation::Reprioritize {
+                id: first_id.clone(),
+                priority: 1,
+            },
+            TaskOperation::Reblock {
+                id: second_id.clone(),
+                blocked_by: vec![first_id.clone()],
+            },
+            TaskOperation::Reblock {
+                id: second_id.clone(),
+                blocked_by: vec![second_id.clone()],
+            },
+            TaskOperation::Close {
+                id: "task-missing".to_string(),
+            },
+        ];
+
+        let results = store.apply_bulk(&ops).unwrap();
+        assert_eq!(
+            results.iter().map(|r| r.ok).collect::<Vec<_>>(),
+            vec![true, true, false, false]
+        );
+        assert_eq!(
+            results[2].error.as_deref(),
+            Some("task cannot block itself")
+        );
+        assert_eq!(results[3].error.as_deref(), Some("task not found"));
+
+        let loaded = TaskStore::load(&path).unwrap();
+        assert_eq!(loaded.get(&first_id).unwrap().priority, 1);
+        assert_eq!(loaded.get(&second_id).unwrap().blocked_by, vec![first_id]);
+    }
+
+    #[test]
+    fn test_apply_bulk_rejects_out_of_range_priority() {
+        let tmp = TempDir::new().unwrap();
+        let path = tmp.path().join("tasks.jsonl");
+
+        let mut store = TaskStore::load(&path).unwrap();
+        let task = Task::new("Task".to_string(), 3);
+        let id = task.id.clone();
+        store.add(task);
+        store.save().unwrap();
+
+        for priority in [0, 9] {
+            let ops = vec![
+                TaskOperation::Close { id: id.clone() },
+                TaskOperation::Reprioritize {
+                    id: id.clone(),
+                    priority,
+                },
+            ];
+            let err = store.apply_bulk(&ops).unwrap_err();
+            assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+            assert!(err.to_string().contains("operation 2"));
+        }
+
+        // The whole batch was rejected, including the valid close
+        let loaded = TaskStore::load(&path).unwrap();
+        assert_eq!(loaded.get(&id).unwrap().status, TaskStatus::Open);
+        assert_eq!(loaded.get(&id).unwrap().priority, 3);
+    }
+
+    #[test]
+    fn test_reblock_rejects_cycles() {
+        let tmp = TempDir::new().unwrap();
+        let mut store = TaskStore::load(&tmp.path().join("tasks.jsonl")).unwrap();
+        let a = store.add(Task::new("A".to_string(), 3)).id.clone();
+        let b = store.add(Task::new("B".to_string(), 3)).id.clone();
+        let c = store.add(Task::new("C".to_string(), 3)).id.clone();
+
+        // C <- B <- A chain: B is blocked by A, C by B
+        let chain = [
+            TaskOperation::Reblock {
+                id: b.clone(),
+                blocked_by: vec![a.clone()],
+            },
+            TaskOperation::Reblock {
+                id: c.clone(),
+                blocked_by: vec![b.clone()],
+            },
+        ];
+        assert!(chain.iter().all(|op| store.apply(op).ok));
+
+        for blocker in [&b, &c] {
+            let result = store.apply(&TaskOperation::Reblock {
+                id: a.clone(),
+                blocked_by: vec![blocker.clone()],
+            });
+            assert!(!result.ok);
+            assert_eq!(result.error.as_deref(), Some("blockers would form a cycle"));
+        }
+        assert!(store.get(&a).unwrap().blocked_by.is_empty());
+    }
+
+    #[test]
+    fn test_task_operation_serde_tag() {
+        let op: TaskOperation =
+            serde_json::from_str(r#"{"op": "delete", "id": "task-1-abcd"}"#).unwrap();
+        assert_eq!(
+            op,
+            TaskOperation::Delete {
+                id: "task-1-abcd".to_string()
+            }
+        );
+    }
+
     #[test]
     fn test_load_skips_malformed_lines() {
         let tmp = TempDir::new().unwrap();
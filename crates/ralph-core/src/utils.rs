@@ -2,7 +2,8 @@
 //!
 //! This module provides shared utilities used across the Ralph orchestrator.
 
-use std::time::Duration;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 /// Formats a duration as MM:SS (minutes:seconds).
 ///
@@ -25,10 +26,46 @@ pub fn format_elapsed(duration: Duration) -> String {
     format!("{mins:02}:{secs:02}")
 }
 
+/// Last `(seconds << 16) | suffix` handed out by [`timestamped_id`].
+static LAST_ID_STAMP: AtomicU64 = AtomicU64::new(0);
+
+/// Generates an ID of the form `{prefix}-{unix_secs}-{hex4}`.
+///
+/// The suffix starts from the current microsecond, but IDs are strictly
+/// increasing within a process, so two IDs generated in quick succession
+/// never collide. If a second's 65536 suffixes run out, the seconds part
+/// advances early.
+pub(crate) fn timestamped_id(prefix: &str) -> String {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("Time went backwards");
+    let candidate = (now.as_secs() << 16) | u64::from(now.subsec_micros() % 0x10000);
+    let previous = LAST_ID_STAMP
+        .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |last| {
+            Some(candidate.max(last + 1))
+        })
+        .expect("update closure always returns Some");
+    let stamp = candidate.max(previous + 1);
+    format!("{prefix}-{}-{:04x}", stamp >> 16, stamp & 0xffff)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn timestamped_ids_are_unique_and_well_formed() {
+        let ids: Vec<String> = (0..1000).map(|_| timestamped_id("task")).collect();
+        let unique: std::collections::HashSet<&String> = ids.iter().collect();
+        assert_eq!(unique.len(), ids.len());
+
+        let parts: Vec<&str> = ids[0].split('-').collect();
+        assert_eq!(parts.len(), 3);
+        assert_eq!(parts[0], "task");
+        assert!(parts[1].parse::<u64>().is_ok());
+        assert_eq!(parts[2].len(), 4);
+    }
+
     #[test]
     fn format_elapsed_zero() {
         assert_eq!(format_elapsed(Duration::from_secs(0)), "00:00");
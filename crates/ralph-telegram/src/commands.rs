@@ -1,4 +1,4 @@
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use crate::bot::escape_html;
 use crate::loop_lock::{LockState, lock_path, lock_state};
@@ -23,6 +23,7 @@ pub fn handle_command(text: &str, workspace_root: &Path) -> Option<String> {
         "/tail" => Some(cmd_tail(workspace_root)),
         "/restart" => Some(cmd_restart(workspace_root)),
         "/stop" => Some(cmd_stop(workspace_root)),
+        "/undo" => Some(cmd_undo(workspace_root)),
         _ => None,
     }
 }
@@ -60,6 +61,7 @@ fn cmd_help() -> String {
         "/tail — Last 20 events",
         "/restart — Restart the orchestration loop",
         "/stop — Stop the orchestration loop",
+        "/undo — Retract your last guidance message, if not yet delivered",
         "/help — This message",
     ]
     .join("\n")
@@ -399,22 +401,117 @@ fn cmd_stop(workspace_root: &Path) -> String {
     }
 }
 
+/// `/undo` — Retract the most recent guidance message.
+///
+/// Appends a `human.guidance.retract` event to the current events file. Only
+/// guidance the event loop hasn't delivered to the agent yet can be retracted,
+/// so the command replies "nothing to undo" when none is pending.
+fn cmd_undo(workspace_root: &Path) -> String {
+    let state = match lock_state(workspace_root) {
+        Ok(state) => state,
+        Err(e) => {
+            return format!(
+                "Failed to check lock state: {}",
+                escape_html(&e.to_string())
+            );
+        }
+    };
+    if state != LockState::Active {
+        return "No active loop to retract guidance from.".to_string();
+    }
+
+    let events_path = match current_events_path(workspace_root) {
+        Ok(path) => path,
+        Err(message) => return message,
+    };
+
+    if pending_guidance(workspace_root, &events_path) == 0 {
+        return "Nothing to undo: no guidance is waiting to be delivered.".to_string();
+    }
+
+    let event = serde_json::json!({
+        "topic": "human.guidance.retract",
+        "payload": "",
+        "ts": chrono::Utc::now().to_rfc3339(),
+    });
+    let result = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&events_path)
+        .and_then(|mut file| {
+            use std::io::Write;
+            writeln!(file, "{}", event)
+        });
+
+    match result {
+        Ok(()) => "Last guidance retracted. It will be dropped at the next iteration.".to_string(),
+        Err(e) => format!(
+            "Failed to write retract event: {}",
+            escape_html(&e.to_string())
+        ),
+    }
+}
+
+/// Counts guidance in the events file that the loop hasn't delivered yet.
+///
+/// The loop records how many guidance and retract events it has taken from
+/// the current events file in `.ralph/agent/guidance-consumed`. Guidance after
+/// that point is pending, less whatever pending retracts already drop.
+fn pending_guidance(workspace_root: &Path, events_path: &Path) -> usize {
+    let current =
+        std::fs::read_to_string(workspace_root.join(".ralph/current-events")).unwrap_or_default();
+    let consumed = std::fs::read_to_string(workspace_root.join(".ralph/agent/guidance-consumed"))
+        .ok()
+        .and_then(|s| serde_json::from_str::<serde_json::Value>(&s).ok())
+        .filter(|marker| marker.get("events").and_then(|v| v.as_str()) == Some(current.trim()))
+        .and_then(|marker| marker.get("consumed").and_then(|v| v.as_u64()))
+        .map_or(0, |n| usize::try_from(n).unwrap_or(usize::MAX));
+
+    let content = std::fs::read_to_string(events_path).unwrap_or_default();
+    content
+        .lines()
+        .filter_map(|line| serde_json::from_str::<serde_json::Value>(line).ok())
+        .filter_map(|event| {
+            let topic = event.get("topic")?.as_str()?;
+            match topic {
+                "human.guidance" => Some(true),
+                "human.guidance.retract" => Some(false),
+                _ => None,
+            }
+        })
+        .skip(consumed)
+        .fold(0, |pending: usize, is_guidance| {
+            if is_guidance {
+                pending + 1
+            } else {
+                pending.saturating_sub(1)
+            }
+        })
+}
+
+/// Resolves the current events file from the `.ralph/current-events` pointer.
+///
+/// Returns a user-facing error message if the pointer can't be read.
+fn current_events_path(workspace_root: &Path) -> Result<PathBuf, String> {
+    let pointer_path = workspace_root.join(".ralph/current-events");
+    if !pointer_path.exists() {
+        return Ok(workspace_root.join(".ralph/events.jsonl"));
+    }
+    std::fs::read_to_string(&pointer_path)
+        .map(|p| workspace_root.join(p.trim()))
+        .map_err(|e| {
+            format!(
+                "Failed to read current-events pointer: {}",
+                escape_html(&e.to_string())
+            )
+        })
+}
+
 /// `/tail` — Last 20 lines of the current events file.
 fn cmd_tail(workspace_root: &Path) -> String {
-    // Find current events file
-    let pointer_path = workspace_root.join(".ralph/current-events");
-    let events_path = if pointer_path.exists() {
-        match std::fs::read_to_string(&pointer_path) {
-            Ok(p) => workspace_root.join(p.trim()),
-            Err(e) => {
-                return format!(
-                    "Failed to read current-events pointer: {}",
-                    escape_html(&e.to_string())
-                );
-            }
-        }
-    } else {
-        workspace_root.join(".ralph/events.jsonl")
+    let events_path = match current_events_path(workspace_root) {
+        Ok(path) => path,
+        Err(message) => return message,
     };
 
     if !events_path.exists() {
@@ -856,4 +953,103 @@ mod tests {
         let result = cmd_help();
         assert!(result.contains("/stop"));
     }
+
+    #[test]
+    fn cmd_undo_no_active_loop() {
+        let dir = TempDir::new().unwrap();
+        setup_workspace(&dir);
+        let result = cmd_undo(dir.path());
+        assert!(result.contains("No active loop"));
+    }
+
+    /// Simulates an active loop writing to `events-20260130-100000.jsonl`.
+    #[cfg(unix)]
+    fn active_loop(dir: &TempDir) -> nix::fcntl::Flock<std::fs::File> {
+        use nix::fcntl::{Flock, FlockArg};
+
+        let lock = serde_json::json!({
+            "pid": 12345,
+            "started": "2026-01-30T10:00:00Z",
+            "prompt": "Test prompt"
+        });
+        let lock_path = dir.path().join(".ralph/loop.lock");
+        std::fs::write(&lock_path, serde_json::to_string(&lock).unwrap()).unwrap();
+
+        let file = std::fs::OpenOptions::new()
+            .read(true)
+            .write(true)
+            .open(&lock_path)
+            .unwrap();
+        std::fs::write(
+            dir.path().join(".ralph/current-events"),
+            ".ralph/events-20260130-100000.jsonl",
+        )
+        .unwrap();
+        Flock::lock(file, FlockArg::LockExclusiveNonblock).unwrap()
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn cmd_undo_appends_retract_event() {
+        let dir = TempDir::new().unwrap();
+        setup_workspace(&dir);
+        let _flock = active_loop(&dir);
+
+        let events_path = dir.path().join(".ralph/events-20260130-100000.jsonl");
+        std::fs::write(
+            &events_path,
+            "{\"topic\":\"human.guidance\",\"payload\":\"Use Postgres\"}\n",
+        )
+        .unwrap();
+
+        let result = cmd_undo(dir.path());
+        assert!(result.contains("retracted"));
+
+        let events = std::fs::read_to_string(&events_path).unwrap();
+        let last = events.lines().last().unwrap();
+        let event: serde_json::Value = serde_json::from_str(last).unwrap();
+        assert_eq!(event["topic"], "human.guidance.retract");
+
+        // The only pending guidance is now retracted
+        assert!(cmd_undo(dir.path()).contains("Nothing to undo"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn cmd_undo_skips_delivered_guidance() {
+        let dir = TempDir::new().unwrap();
+        setup_workspace(&dir);
+        let _flock = active_loop(&dir);
+
+        let events_path = dir.path().join(".ralph/events-20260130-100000.jsonl");
+        std::fs::write(
+            &events_path,
+            "{\"topic\":\"human.guidance\",\"payload\":\"Use Postgres\"}\n",
+        )
+        .unwrap();
+        std::fs::write(
+            dir.path().join(".ralph/agent/guidance-consumed"),
+            r#"{"events":".ralph/events-20260130-100000.jsonl","consumed":1}"#,
+        )
+        .unwrap();
+
+        let result = cmd_undo(dir.path());
+        assert!(result.contains("Nothing to undo"));
+        let events = std::fs::read_to_string(&events_path).unwrap();
+        assert!(!events.contains("human.guidance.retract"));
+
+        // A marker from an earlier run doesn't count against this one
+        std::fs::write(
+            dir.path().join(".ralph/agent/guidance-consumed"),
+            r#"{"events":".ralph/events-20260129-090000.jsonl","consumed":1}"#,
+        )
+        .unwrap();
+        assert!(cmd_undo(dir.path()).contains("retracted"));
+    }
+
+    #[test]
+    fn cmd_help_lists_undo() {
+        let result = cmd_help();
+        assert!(result.contains("/undo"));
+    }
 }